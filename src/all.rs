@@ -0,0 +1,50 @@
+use crate::GetManyMutExt;
+use core::slice;
+
+/// Extension trait for [`get_all_mut`](GetAllMutExt::get_all_mut).
+///
+/// The lifetime parameter allows the output type to borrow from `self`.
+pub trait GetAllMutExt<'a> {
+    type Output;
+    /// Returns mutable references to every element, without any checks.
+    ///
+    /// For arrays this returns an array of references, like
+    /// `array::each_mut`. For slices this returns an iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetAllMutExt;
+    ///
+    /// let mut v = [1, 2, 3];
+    /// let [a, b, c] = v.get_all_mut();
+    /// core::mem::swap(a, c);
+    /// *b *= 10;
+    /// assert_eq!(v, [3, 20, 1]);
+    ///
+    /// for x in v[1..].get_all_mut() {
+    ///     *x += 1;
+    /// }
+    /// assert_eq!(v, [3, 21, 2]);
+    /// ```
+    fn get_all_mut(&'a mut self) -> Self::Output;
+}
+
+impl<'a, T: 'a> GetAllMutExt<'a> for [T] {
+    type Output = slice::IterMut<'a, T>;
+    fn get_all_mut(&'a mut self) -> slice::IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<'a, T: 'a, const M: usize> GetAllMutExt<'a> for [T; M] {
+    type Output = [&'a mut T; M];
+    fn get_all_mut(&'a mut self) -> [&'a mut T; M] {
+        let mut indices = [0; M];
+        for (i, idx) in indices.iter_mut().enumerate() {
+            *idx = i;
+        }
+        // SAFETY: `0..M` are distinct and in bounds.
+        unsafe { self.get_many_unchecked_mut(indices) }
+    }
+}
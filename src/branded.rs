@@ -0,0 +1,150 @@
+use crate::{GetManyMutError, GetManyMutExt, get_many_check_valid};
+use core::marker::PhantomData;
+
+/// An invariant lifetime, used to tie indices to the slice they were checked
+/// against.
+type Brand<'brand> = PhantomData<fn(&'brand ()) -> &'brand ()>;
+
+/// Calls `f` with a [`BrandedSlice`] wrapping `slice`.
+///
+/// Each call uses a fresh `'brand` lifetime, so indices checked against one
+/// [`BrandedSlice`] cannot be used with any other.
+///
+/// # Examples
+///
+/// ```
+/// use get_many_mut::with_branded;
+///
+/// let v = &mut [1, 2, 3, 4];
+/// with_branded(v, |mut v| {
+///     let indices = v.indices([3, 0]).unwrap();
+///     for _ in 0..10 {
+///         // No checks are done here.
+///         let [a, b] = v.get_many_mut(&indices);
+///         *a += *b;
+///     }
+/// });
+/// assert_eq!(v, &[1, 2, 3, 14]);
+/// ```
+///
+/// Indices cannot escape their closure, or be used with a different slice.
+///
+/// ```compile_fail
+/// use get_many_mut::with_branded;
+///
+/// let v = &mut [1, 2, 3];
+/// let w = &mut [4, 5, 6];
+/// with_branded(v, |v| {
+///     let index = v.index(0).unwrap();
+///     with_branded(w, |mut w| {
+///         w.get_mut(index);
+///     });
+/// });
+/// ```
+pub fn with_branded<'a, T, R, F>(slice: &'a mut [T], f: F) -> R
+where
+    F: for<'brand> FnOnce(BrandedSlice<'brand, 'a, T>) -> R,
+{
+    f(BrandedSlice { slice, brand: PhantomData })
+}
+
+/// A slice whose indices can be checked once and then used without further
+/// checks.
+///
+/// See [`with_branded`].
+pub struct BrandedSlice<'brand, 'a, T> {
+    slice: &'a mut [T],
+    brand: Brand<'brand>,
+}
+
+/// An index that is in bounds for the [`BrandedSlice`] with the same `'brand`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BrandedIndex<'brand> {
+    index: usize,
+    brand: Brand<'brand>,
+}
+
+/// Distinct indices that are in bounds for the [`BrandedSlice`] with the same
+/// `'brand`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BrandedIndices<'brand, const N: usize> {
+    indices: [usize; N],
+    brand: Brand<'brand>,
+}
+
+impl<'brand, 'a, T> BrandedSlice<'brand, 'a, T> {
+    /// Returns the number of elements in the slice.
+    pub fn len(&self) -> usize {
+        self.slice.len()
+    }
+
+    /// Returns `true` if the slice has a length of 0.
+    pub fn is_empty(&self) -> bool {
+        self.slice.is_empty()
+    }
+
+    /// Checks that `index` is in bounds.
+    pub fn index(&self, index: usize) -> Option<BrandedIndex<'brand>> {
+        if index < self.slice.len() {
+            Some(BrandedIndex { index, brand: PhantomData })
+        } else {
+            None
+        }
+    }
+
+    /// Checks that `indices` are in bounds and pairwise distinct.
+    ///
+    /// Returns an error if any index is out-of-bounds, or if the same index
+    /// was passed more than once.
+    pub fn indices<const N: usize>(
+        &self,
+        indices: [usize; N],
+    ) -> Result<BrandedIndices<'brand, N>, GetManyMutError<N>> {
+        if get_many_check_valid(&indices, self.slice.len()) {
+            Ok(BrandedIndices { indices, brand: PhantomData })
+        } else {
+            Err(GetManyMutError::new())
+        }
+    }
+
+    /// Returns a reference to the element at `index`, without any checks.
+    pub fn get(&self, index: BrandedIndex<'brand>) -> &T {
+        // SAFETY: `index` was checked against this slice.
+        unsafe { self.slice.get_unchecked(index.index) }
+    }
+
+    /// Returns a mutable reference to the element at `index`, without any
+    /// checks.
+    pub fn get_mut(&mut self, index: BrandedIndex<'brand>) -> &mut T {
+        // SAFETY: `index` was checked against this slice.
+        unsafe { self.slice.get_unchecked_mut(index.index) }
+    }
+
+    /// Returns mutable references to many indices at once, without any checks.
+    pub fn get_many_mut<const N: usize>(
+        &mut self,
+        indices: &BrandedIndices<'brand, N>,
+    ) -> [&mut T; N] {
+        // SAFETY: `indices` were checked against this slice.
+        unsafe { self.slice.get_many_unchecked_mut(indices.indices) }
+    }
+
+    /// Returns the underlying slice.
+    pub fn into_inner(self) -> &'a mut [T] {
+        self.slice
+    }
+}
+
+impl<'brand> BrandedIndex<'brand> {
+    /// Returns the index as a `usize`.
+    pub fn get(self) -> usize {
+        self.index
+    }
+}
+
+impl<'brand, const N: usize> BrandedIndices<'brand, N> {
+    /// Returns the indices as `usize`s.
+    pub fn get(&self) -> [usize; N] {
+        self.indices
+    }
+}
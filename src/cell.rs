@@ -0,0 +1,126 @@
+//! This module contains no `unsafe` code.
+#![forbid(unsafe_code)]
+
+use crate::GetManyMutError;
+use core::{cell::Cell, mem};
+
+/// A mutable slice viewed as a slice of [`Cell`]s.
+///
+/// [`get_many`](SliceCell::get_many) returns shared references to cells, so
+/// the indices do not have to be distinct, and the cells from several calls
+/// can be used together. [`SliceCell::get_many_mut`] returns mutable
+/// references from a mutable slice instead, and checks that the indices are
+/// distinct.
+///
+/// Unlike the rest of this crate, this is implemented without any `unsafe`
+/// code, on top of [`Cell::from_mut`] and [`Cell::as_slice_of_cells`]. The
+/// rest of the crate is still compiled, so this does not make the crate usable
+/// by projects that forbid `unsafe` code in all of their dependencies.
+///
+/// # Examples
+///
+/// ```
+/// use get_many_mut::SliceCell;
+///
+/// let mut v = [1, 2, 3];
+/// let cells = SliceCell::new(&mut v);
+///
+/// let [a, b] = cells.get_many([0, 2]).unwrap();
+/// let [c] = cells.get_many([0]).unwrap();
+/// a.set(b.get() + c.get());
+///
+/// let [x, y] = SliceCell::get_many_mut(&mut v, [2, 0]).unwrap();
+/// core::mem::swap(x, y);
+///
+/// assert_eq!(v, [3, 2, 4]);
+/// ```
+pub struct SliceCell<'a, T> {
+    cells: &'a [Cell<T>],
+}
+
+impl<T> Clone for SliceCell<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for SliceCell<'_, T> {}
+
+impl<'a, T> SliceCell<'a, T> {
+    /// Wraps a mutable slice.
+    pub fn new(slice: &'a mut [T]) -> Self {
+        Self { cells: Cell::from_mut(slice).as_slice_of_cells() }
+    }
+
+    /// Returns the number of elements in the slice.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns `true` if the slice has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the slice as a slice of cells.
+    pub fn as_cells(&self) -> &'a [Cell<T>] {
+        self.cells
+    }
+
+    /// Returns references to the cells at many indices at once.
+    ///
+    /// The same index may be passed more than once.
+    ///
+    /// Returns an error if any index is out-of-bounds.
+    pub fn get_many<const N: usize>(
+        &self,
+        indices: [usize; N],
+    ) -> Result<[&'a Cell<T>; N], GetManyMutError<N>> {
+        let cells = self.cells;
+        if indices.iter().all(|&idx| idx < cells.len()) {
+            Ok(indices.map(|idx| &cells[idx]))
+        } else {
+            Err(GetManyMutError::new())
+        }
+    }
+
+    /// Returns mutable references to many indices of a mutable slice at once.
+    ///
+    /// This takes the slice itself rather than a `SliceCell`, since mutable
+    /// references can't be made from cells without `unsafe` code.
+    ///
+    /// Returns an error if any index is out-of-bounds, or if the same index was
+    /// passed more than once.
+    ///
+    /// This sorts the indices, and then splits the slice at each of them, so
+    /// it does `O(N log N)` operations.
+    pub fn get_many_mut<const N: usize>(
+        slice: &mut [T],
+        indices: [usize; N],
+    ) -> Result<[&mut T; N], GetManyMutError<N>> {
+        let mut order = [0; N];
+        for (i, pos) in order.iter_mut().enumerate() {
+            *pos = i;
+        }
+        order.sort_unstable_by_key(|&pos| indices[pos]);
+
+        let mut refs: [Option<&mut T>; N] = [(); N].map(|()| None);
+        let mut rest: &mut [T] = slice;
+        let mut offset = 0;
+        for &pos in &order {
+            // NB: `indices` are visited in ascending order, so a duplicate
+            // index is less than `offset`.
+            let skip = indices[pos]
+                .checked_sub(offset)
+                .ok_or_else(GetManyMutError::new)?;
+            let (elem, tail) = mem::take(&mut rest)
+                .get_mut(skip..)
+                .and_then(<[T]>::split_first_mut)
+                .ok_or_else(GetManyMutError::new)?;
+            refs[pos] = Some(elem);
+            rest = tail;
+            offset = indices[pos] + 1;
+        }
+        Ok(refs.map(|elem| elem.expect("every index was visited")))
+    }
+}
@@ -0,0 +1,74 @@
+/// Returns `true` if every index in `indices` is less than `len`, and no
+/// index appears more than once.
+///
+/// This is the same check that [`get_many_mut`] does, exposed so that it can
+/// be reused, including in `const` contexts. It does every pairwise
+/// comparison, so it is `O(N²)` in the number of indices.
+///
+/// # Examples
+///
+/// ```
+/// use get_many_mut::indices_are_disjoint;
+///
+/// const TABLE: [usize; 3] = [4, 0, 2];
+/// const VALID: bool = indices_are_disjoint(&TABLE, 5);
+/// assert!(VALID);
+///
+/// assert!(indices_are_disjoint(&[], 0));
+/// assert!(!indices_are_disjoint(&[0, 5], 5));
+/// assert!(!indices_are_disjoint(&[1, 1], 5));
+/// ```
+///
+/// [`get_many_mut`]: crate::GetManyMutExt::get_many_mut
+pub const fn indices_are_disjoint(indices: &[usize], len: usize) -> bool {
+    let mut i = 0;
+    while i < indices.len() {
+        if indices[i] >= len {
+            return false;
+        }
+        let mut j = 0;
+        while j < i {
+            if indices[i] == indices[j] {
+                return false;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns `true` if `indices` is strictly ascending, and every index is less
+/// than `len`.
+///
+/// Strictly ascending indices are always disjoint, so this is a cheaper
+/// alternative to [`indices_are_disjoint`] for indices that are known to be
+/// sorted. It is `O(N)` in the number of indices, and returns `false` for
+/// unsorted indices even if they are disjoint.
+///
+/// # Examples
+///
+/// ```
+/// use get_many_mut::sorted_indices_are_disjoint;
+///
+/// const TABLE: [usize; 3] = [0, 2, 4];
+/// const VALID: bool = sorted_indices_are_disjoint(&TABLE, 5);
+/// assert!(VALID);
+///
+/// assert!(!sorted_indices_are_disjoint(&[0, 5], 5));
+/// assert!(!sorted_indices_are_disjoint(&[1, 1], 5));
+/// assert!(!sorted_indices_are_disjoint(&[2, 1], 5));
+/// ```
+pub const fn sorted_indices_are_disjoint(
+    indices: &[usize],
+    len: usize,
+) -> bool {
+    let mut i = 0;
+    while i < indices.len() {
+        if indices[i] >= len || (i > 0 && indices[i - 1] >= indices[i]) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
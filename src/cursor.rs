@@ -0,0 +1,122 @@
+use crate::GetManyMutError;
+use alloc::{vec, vec::Vec};
+use core::{marker::PhantomData, ptr::NonNull};
+
+const BITS: usize = usize::BITS as usize;
+
+/// A mutable slice that hands out references to one element at a time, making
+/// sure that no element is handed out twice.
+///
+/// Unlike [`get_many_mut`], the indices do not need to be known up front, and
+/// the references live for as long as the original borrow of the slice.
+///
+/// This keeps track of which elements have been claimed using one bit per
+/// element.
+///
+/// # Examples
+///
+/// ```
+/// use get_many_mut::DisjointCursor;
+///
+/// let v = &mut [1, 2, 3, 4];
+/// let mut cursor = DisjointCursor::new(v);
+/// let a = cursor.claim(3).unwrap();
+/// let b = cursor.claim(0).unwrap();
+/// assert!(cursor.claim(3).is_none());
+/// assert!(cursor.claim(4).is_none());
+/// *a += *b;
+/// *b = 0;
+/// assert_eq!(v, &[0, 2, 3, 5]);
+/// ```
+///
+/// [`get_many_mut`]: crate::GetManyMutExt::get_many_mut
+pub struct DisjointCursor<'a, T> {
+    ptr: NonNull<T>,
+    len: usize,
+    claimed: Vec<usize>,
+    marker: PhantomData<&'a mut [T]>,
+}
+
+// SAFETY: `DisjointCursor` behaves like `&'a mut [T]`.
+unsafe impl<'a, T: Send> Send for DisjointCursor<'a, T> {}
+unsafe impl<'a, T: Sync> Sync for DisjointCursor<'a, T> {}
+
+impl<'a, T> DisjointCursor<'a, T> {
+    /// Creates a cursor with no claimed elements.
+    pub fn new(slice: &'a mut [T]) -> Self {
+        let len = slice.len();
+        let words = len / BITS + (len % BITS != 0) as usize;
+        Self {
+            ptr: NonNull::from(slice).cast(),
+            len,
+            claimed: vec![0; words],
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of elements in the underlying slice.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the underlying slice has a length of 0.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the element at `index` has already been claimed.
+    ///
+    /// Out-of-bounds indices are never claimed.
+    pub fn is_claimed(&self, index: usize) -> bool {
+        index < self.len
+            && self.claimed[index / BITS] & (1 << (index % BITS)) != 0
+    }
+
+    /// Returns a mutable reference to the element at `index`, and marks it as
+    /// claimed.
+    ///
+    /// Returns `None` if `index` is out-of-bounds, or if it has already been
+    /// claimed.
+    pub fn claim(&mut self, index: usize) -> Option<&'a mut T> {
+        if index >= self.len {
+            return None;
+        }
+        let word = &mut self.claimed[index / BITS];
+        let bit = 1 << (index % BITS);
+        if *word & bit != 0 {
+            return None;
+        }
+        *word |= bit;
+        // SAFETY: `index` is in bounds, and has not been handed out before.
+        unsafe { Some(&mut *self.ptr.as_ptr().add(index)) }
+    }
+}
+
+/// An iterator over mutable references to the elements at each index of `I`.
+///
+/// This `struct` is created by the [`iter_many_mut`] method.
+///
+/// [`iter_many_mut`]: crate::GetManyMutExt::iter_many_mut
+pub struct IterManyMut<'a, T, I> {
+    cursor: DisjointCursor<'a, T>,
+    indices: I,
+}
+
+impl<'a, T, I> IterManyMut<'a, T, I> {
+    pub(crate) fn new(slice: &'a mut [T], indices: I) -> Self {
+        Self { cursor: DisjointCursor::new(slice), indices }
+    }
+}
+
+impl<'a, T, I: Iterator<Item = usize>> Iterator for IterManyMut<'a, T, I> {
+    type Item = Result<&'a mut T, GetManyMutError<1>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.indices.next()?;
+        Some(self.cursor.claim(index).ok_or_else(GetManyMutError::new))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
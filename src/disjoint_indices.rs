@@ -0,0 +1,128 @@
+use crate::{GetManyMutError, get_many_check_valid};
+
+/// An array of indices that has been checked to be pairwise distinct and less
+/// than some length.
+///
+/// Passing this to [`get_many_mut_with`] only has to check that the slice is
+/// at least [`min_len`] long, so the pairwise check is only done once, in
+/// [`DisjointIndices::new`].
+///
+/// # Examples
+///
+/// ```
+/// use get_many_mut::{DisjointIndices, GetManyMutExt};
+///
+/// let indices = DisjointIndices::new([0, 2], 3).unwrap();
+///
+/// let mut rows = [[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+/// for row in &mut rows {
+///     let [a, b] = row.get_many_mut_with(&indices).unwrap();
+///     core::mem::swap(a, b);
+/// }
+/// assert_eq!(rows, [[3, 2, 1], [6, 5, 4], [9, 8, 7]]);
+///
+/// assert!(DisjointIndices::new([0, 0], 3).is_err());
+/// assert!(DisjointIndices::new([0, 3], 3).is_err());
+/// assert!([1, 2].get_many_mut_with(&indices).is_err());
+/// ```
+///
+/// [`get_many_mut_with`]: crate::GetManyMutExt::get_many_mut_with
+/// [`min_len`]: DisjointIndices::min_len
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DisjointIndices<const N: usize> {
+    indices: [usize; N],
+    len: usize,
+}
+
+impl<const N: usize> DisjointIndices<N> {
+    /// Checks that `indices` are pairwise distinct and all less than `len`.
+    ///
+    /// Returns an error if any index is out-of-bounds, or if the same index
+    /// was passed more than once.
+    pub fn new(
+        indices: [usize; N],
+        len: usize,
+    ) -> Result<Self, GetManyMutError<N>> {
+        if get_many_check_valid(&indices, len) {
+            Ok(Self { indices, len })
+        } else {
+            Err(GetManyMutError::new())
+        }
+    }
+
+    /// Returns the validated indices.
+    pub const fn indices(&self) -> &[usize; N] {
+        &self.indices
+    }
+
+    /// Returns the length the indices were validated against.
+    ///
+    /// The indices are valid for any slice at least this long.
+    pub const fn min_len(&self) -> usize {
+        self.len
+    }
+}
+
+/// A set of indices that is known to be pairwise distinct and less than some
+/// length.
+///
+/// Implementing this for your own index types lets them be passed to
+/// [`get_many_mut_with`], which then only has to check the length of the
+/// slice, instead of checking the indices against each other.
+///
+/// # Safety
+///
+/// Every array returned by [`indices`](TrustedDisjointIndices::indices) must
+/// contain pairwise distinct indices, that are all less than every value
+/// returned by [`min_len`](TrustedDisjointIndices::min_len) for the same
+/// value.
+///
+/// # Examples
+///
+/// ```
+/// use get_many_mut::{GetManyMutExt, TrustedDisjointIndices};
+///
+/// /// Two indices `a < b`.
+/// struct Ascending(usize, usize);
+///
+/// impl Ascending {
+///     fn new(a: usize, b: usize) -> Option<Self> {
+///         (a < b).then(|| Ascending(a, b))
+///     }
+/// }
+///
+/// // SAFETY: `a < b`, so they are distinct and both less than `b + 1`.
+/// unsafe impl TrustedDisjointIndices<2> for Ascending {
+///     fn indices(&self) -> [usize; 2] {
+///         [self.0, self.1]
+///     }
+///     fn min_len(&self) -> usize {
+///         self.1 + 1
+///     }
+/// }
+///
+/// let v = &mut [1, 2, 3];
+/// let [a, b] = v.get_many_mut_with(&Ascending::new(0, 2).unwrap()).unwrap();
+/// core::mem::swap(a, b);
+/// assert_eq!(v, &[3, 2, 1]);
+///
+/// assert!(v.get_many_mut_with(&Ascending::new(1, 3).unwrap()).is_err());
+/// ```
+///
+/// [`get_many_mut_with`]: crate::GetManyMutExt::get_many_mut_with
+pub unsafe trait TrustedDisjointIndices<const N: usize> {
+    /// Returns the indices.
+    fn indices(&self) -> [usize; N];
+    /// Returns a length that all indices are less than.
+    fn min_len(&self) -> usize;
+}
+
+// SAFETY: The indices were checked in `DisjointIndices::new`.
+unsafe impl<const N: usize> TrustedDisjointIndices<N> for DisjointIndices<N> {
+    fn indices(&self) -> [usize; N] {
+        self.indices
+    }
+    fn min_len(&self) -> usize {
+        self.len
+    }
+}
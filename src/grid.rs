@@ -0,0 +1,81 @@
+use crate::GetManyMutError;
+
+/// This checks every coordinate against each other, and against `rows` and
+/// `C`.
+///
+/// This will do the same number of comparisons as
+/// [`get_many_check_valid_pairwise`](crate::get_many_check_valid_pairwise).
+fn get_many_check_valid_2d<const C: usize, const N: usize>(
+    coords: &[(usize, usize); N],
+    rows: usize,
+) -> bool {
+    let mut valid = true;
+    for (i, &(row, col)) in coords.iter().enumerate() {
+        valid &= (row < rows) & (col < C);
+        for &(row2, col2) in &coords[..i] {
+            valid &= (row != row2) | (col != col2);
+        }
+    }
+    valid
+}
+
+/// Extension trait for [`get_many_mut_2d`](GetManyMut2dExt::get_many_mut_2d).
+///
+/// For a flat slice with a runtime row width, see
+/// [`get_many_mut_2d_flat`](crate::GetManyMutExt::get_many_mut_2d_flat).
+pub trait GetManyMut2dExt {
+    type Element;
+    /// Returns mutable references to many `(row, column)` coordinates at once.
+    ///
+    /// Returns an error if any coordinate is out-of-bounds, or if the same
+    /// coordinate was passed more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMut2dExt;
+    ///
+    /// let mut grid = [[0; 3]; 2];
+    /// let [cell, right, below] =
+    ///     grid.get_many_mut_2d([(0, 1), (0, 2), (1, 1)]).unwrap();
+    /// *cell = 1;
+    /// *right = 2;
+    /// *below = 3;
+    /// assert_eq!(grid, [[0, 1, 2], [0, 3, 0]]);
+    ///
+    /// assert!(grid.get_many_mut_2d([(0, 3)]).is_err());
+    /// assert!(grid.get_many_mut_2d([(2, 0)]).is_err());
+    /// assert!(grid.get_many_mut_2d([(1, 1), (1, 1)]).is_err());
+    /// ```
+    fn get_many_mut_2d<const N: usize>(
+        &mut self,
+        coords: [(usize, usize); N],
+    ) -> Result<[&mut Self::Element; N], GetManyMutError<N>>;
+}
+
+impl<T, const C: usize> GetManyMut2dExt for [[T; C]] {
+    type Element = T;
+    fn get_many_mut_2d<const N: usize>(
+        &mut self,
+        coords: [(usize, usize); N],
+    ) -> Result<[&mut T; N], GetManyMutError<N>> {
+        if !get_many_check_valid_2d::<C, N>(&coords, self.len()) {
+            return Err(GetManyMutError::new());
+        }
+        let ptr: *mut [T; C] = self.as_mut_ptr();
+        // SAFETY: The coordinates are distinct and in bounds.
+        unsafe {
+            Ok(coords.map(|(row, col)| &mut *ptr.add(row).cast::<T>().add(col)))
+        }
+    }
+}
+
+impl<T, const C: usize, const R: usize> GetManyMut2dExt for [[T; C]; R] {
+    type Element = T;
+    fn get_many_mut_2d<const N: usize>(
+        &mut self,
+        coords: [(usize, usize); N],
+    ) -> Result<[&mut T; N], GetManyMutError<N>> {
+        <[[T; C]] as GetManyMut2dExt>::get_many_mut_2d(self, coords)
+    }
+}
@@ -0,0 +1,147 @@
+use crate::GetManyMutError;
+use core::hash::{BuildHasher, Hash};
+use std::collections::HashMap;
+
+/// Extension trait for [`entry_many`](EntryManyExt::entry_many).
+///
+/// This is separate from [`GetManyMutExt`](crate::GetManyMutExt) because maps
+/// are indexed by keys, and can insert missing ones.
+pub trait EntryManyExt<K, V, S> {
+    /// Gets the entries for many keys at once, for in-place insertion or
+    /// update.
+    ///
+    /// Returns an error if the same key was passed more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::EntryManyExt;
+    /// use std::collections::HashMap;
+    ///
+    /// let mut counts = HashMap::new();
+    /// counts.insert("a", 1);
+    ///
+    /// let [a, b] = counts.entry_many(["a", "b"]).unwrap().or_default();
+    /// *a += 1;
+    /// *b += 1;
+    /// assert_eq!(counts["a"], 2);
+    /// assert_eq!(counts["b"], 1);
+    ///
+    /// assert!(counts.entry_many(["a", "a"]).is_err());
+    /// ```
+    fn entry_many<const N: usize>(
+        &mut self,
+        keys: [K; N],
+    ) -> Result<EntryMany<'_, K, V, S, N>, GetManyMutError<N>>;
+}
+
+impl<K: Eq + Hash + Clone, V, S: BuildHasher> EntryManyExt<K, V, S>
+    for HashMap<K, V, S>
+{
+    fn entry_many<const N: usize>(
+        &mut self,
+        keys: [K; N],
+    ) -> Result<EntryMany<'_, K, V, S, N>, GetManyMutError<N>> {
+        for (i, key) in keys.iter().enumerate() {
+            if keys[..i].contains(key) {
+                return Err(GetManyMutError::new());
+            }
+        }
+        Ok(EntryMany { map: self, keys })
+    }
+}
+
+/// The entries for many distinct keys of a [`HashMap`].
+///
+/// This is returned by [`entry_many`](EntryManyExt::entry_many).
+// NB: This can't be an array of `std`'s `Entry`s, since each of those
+// borrows the whole map.
+pub struct EntryMany<'a, K, V, S, const N: usize> {
+    map: &'a mut HashMap<K, V, S>,
+    keys: [K; N],
+}
+
+impl<'a, K: Eq + Hash + Clone, V, S: BuildHasher, const N: usize>
+    EntryMany<'a, K, V, S, N>
+{
+    /// Returns the keys of the entries.
+    pub fn keys(&self) -> &[K; N] {
+        &self.keys
+    }
+
+    /// Inserts `default(key)` for every key that is not in the map, and
+    /// returns mutable references to the values of all keys.
+    ///
+    /// # Panics
+    ///
+    /// Panics if two of the keys refer to the same value in the map. This can
+    /// only happen if the `Eq` or `Hash` implementation of `K` is incorrect.
+    ///
+    /// ```should_panic
+    /// use get_many_mut::EntryManyExt;
+    /// use std::{
+    ///     collections::HashMap,
+    ///     hash::{Hash, Hasher},
+    /// };
+    ///
+    /// /// `K(0)` is equal to every key, so equality is not transitive.
+    /// #[derive(Clone)]
+    /// struct K(u32);
+    /// impl PartialEq for K {
+    ///     fn eq(&self, other: &Self) -> bool {
+    ///         self.0 == other.0 || self.0 == 0 || other.0 == 0
+    ///     }
+    /// }
+    /// impl Eq for K {}
+    /// impl Hash for K {
+    ///     fn hash<H: Hasher>(&self, _: &mut H) {}
+    /// }
+    ///
+    /// let mut map = HashMap::new();
+    /// map.insert(K(0), 0);
+    /// // `K(1)` and `K(2)` both find the value of `K(0)`.
+    /// let _ = map.entry_many([K(1), K(2)]).unwrap().or_default();
+    /// ```
+    pub fn or_insert_with<F: FnMut(&K) -> V>(
+        self,
+        mut default: F,
+    ) -> [&'a mut V; N] {
+        // NB: Every insertion has to happen before the first reference is
+        // taken, since inserting may move the other values.
+        for key in &self.keys {
+            if !self.map.contains_key(key) {
+                let value = default(key);
+                self.map.insert(key.clone(), value);
+            }
+        }
+        let map = self.map;
+        let values = self.keys.map(|key| -> *mut V {
+            map.get_mut(&key).expect("key was inserted")
+        });
+        // NB: The keys are distinct according to `Eq`, but a safe `Eq` or
+        // `Hash` implementation can be incorrect, so that distinct keys find
+        // the same value. References to zero-sized values can't overlap.
+        if core::mem::size_of::<V>() != 0 {
+            for (i, value) in values.iter().enumerate() {
+                if values[..i].contains(value) {
+                    panic!("distinct keys refer to the same value");
+                }
+            }
+        }
+        values.map(|value| -> &'a mut V {
+            // SAFETY: The pointers were checked to be distinct (or to point to
+            // zero-sized values), and the map is not modified while the
+            // references are alive.
+            unsafe { &mut *value }
+        })
+    }
+
+    /// Inserts [`V::default()`](Default::default) for every key that is not
+    /// in the map, and returns mutable references to the values of all keys.
+    pub fn or_default(self) -> [&'a mut V; N]
+    where
+        V: Default,
+    {
+        self.or_insert_with(|_| V::default())
+    }
+}
@@ -0,0 +1,80 @@
+/// A type that can be used as an index, see
+/// [`get_many_typed_mut`](crate::GetManyMutExt::get_many_typed_mut).
+///
+/// This is implemented for every `Copy` type that can be converted to `usize`
+/// with [`TryInto`], including all primitive integer types.
+///
+/// Since `Into<usize>` implies `TryInto<usize>`, strongly-typed index
+/// newtypes like those from `index_vec` or `typed-index-collections` can be
+/// used directly, as long as they implement `From<Newtype> for usize`.
+///
+/// # Examples
+///
+/// ```
+/// use get_many_mut::GetManyMutExt;
+///
+/// #[derive(Clone, Copy)]
+/// struct NodeId(u32);
+///
+/// impl From<NodeId> for usize {
+///     fn from(id: NodeId) -> usize {
+///         id.0 as usize
+///     }
+/// }
+///
+/// let nodes = &mut ["a", "b", "c"];
+/// let [a, c] = nodes.get_many_typed_mut([NodeId(0), NodeId(2)]).unwrap();
+/// core::mem::swap(a, c);
+/// assert_eq!(nodes, &["c", "b", "a"]);
+/// ```
+pub trait IndexType: Copy + TryInto<usize> {}
+
+impl<I: Copy + TryInto<usize>> IndexType for I {}
+
+/// Converts every index to `usize`.
+///
+/// Returns `None` if any index cannot be converted.
+pub(crate) fn to_usize_indices<I: IndexType, const N: usize>(
+    indices: [I; N],
+) -> Option<[usize; N]> {
+    let mut converted = [0; N];
+    for (idx, index) in converted.iter_mut().zip(indices) {
+        *idx = index.try_into().ok()?;
+    }
+    Some(converted)
+}
+
+/// An index counted from either end of a slice.
+///
+/// See [`get_many_rel_mut`](crate::GetManyMutExt::get_many_rel_mut).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RelIndex {
+    /// The index counted from the start, so `FromStart(0)` is the first
+    /// element.
+    FromStart(usize),
+    /// The index counted from the end, so `FromEnd(0)` is the last element.
+    FromEnd(usize),
+}
+
+impl RelIndex {
+    /// Returns the absolute index for a slice of length `len`.
+    ///
+    /// Returns `None` if the index is out-of-bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::RelIndex;
+    ///
+    /// assert_eq!(RelIndex::FromStart(1).resolve(3), Some(1));
+    /// assert_eq!(RelIndex::FromEnd(0).resolve(3), Some(2));
+    /// assert_eq!(RelIndex::FromEnd(3).resolve(3), None);
+    /// ```
+    pub const fn resolve(self, len: usize) -> Option<usize> {
+        match self {
+            RelIndex::FromStart(idx) if idx < len => Some(idx),
+            RelIndex::FromEnd(idx) if idx < len => Some(len - 1 - idx),
+            _ => None,
+        }
+    }
+}
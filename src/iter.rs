@@ -0,0 +1,281 @@
+use core::{iter::Zip, marker::PhantomData, ptr::NonNull, slice};
+
+/// An iterator over mutable references to the elements selected by a mask.
+///
+/// This `struct` is created by the [`select_mask_mut`] method.
+///
+/// [`select_mask_mut`]: crate::GetManyMutExt::select_mask_mut
+pub struct SelectMaskMut<'a, 'b, T> {
+    inner: Zip<slice::IterMut<'a, T>, slice::Iter<'b, bool>>,
+}
+
+impl<'a, 'b, T> SelectMaskMut<'a, 'b, T> {
+    pub(crate) fn new(slice: &'a mut [T], mask: &'b [bool]) -> Self {
+        Self { inner: slice.iter_mut().zip(mask) }
+    }
+}
+
+impl<'a, 'b, T> Iterator for SelectMaskMut<'a, 'b, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.find_map(
+            |(elem, &selected)| if selected { Some(elem) } else { None },
+        )
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+impl<'a, 'b, T> DoubleEndedIterator for SelectMaskMut<'a, 'b, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            let (elem, &selected) = self.inner.next_back()?;
+            if selected {
+                return Some(elem);
+            }
+        }
+    }
+}
+
+mod sealed {
+    pub trait Sealed: Copy {
+        /// Returns the index of the lowest set bit, and clears it.
+        fn take_lowest(&mut self) -> Option<usize>;
+        /// Returns the index of the highest set bit, and clears it.
+        fn take_highest(&mut self) -> Option<usize>;
+        /// Returns the index of the highest set bit.
+        fn highest(self) -> Option<usize>;
+        /// Returns the number of set bits.
+        fn count(self) -> usize;
+    }
+}
+
+/// An unsigned integer whose set bits select indices.
+///
+/// This trait is sealed, and implemented for all unsigned integer types.
+pub trait BitMask: sealed::Sealed {}
+
+macro_rules! impl_bit_mask {
+    ($($ty:ty),*) => {$(
+        impl sealed::Sealed for $ty {
+            fn take_lowest(&mut self) -> Option<usize> {
+                if *self == 0 {
+                    return None;
+                }
+                let index = self.trailing_zeros() as usize;
+                *self &= *self - 1;
+                Some(index)
+            }
+            fn take_highest(&mut self) -> Option<usize> {
+                let index = self.highest()?;
+                *self &= !(1 << index);
+                Some(index)
+            }
+            fn highest(self) -> Option<usize> {
+                if self == 0 {
+                    None
+                } else {
+                    Some((<$ty>::BITS - 1 - self.leading_zeros()) as usize)
+                }
+            }
+            fn count(self) -> usize {
+                self.count_ones() as usize
+            }
+        }
+        impl BitMask for $ty {}
+    )*};
+}
+
+impl_bit_mask!(u8, u16, u32, u64, u128, usize);
+
+/// An iterator over mutable references to the elements selected by the set
+/// bits of a [`BitMask`], in ascending order.
+///
+/// This `struct` is created by the [`get_masked_mut`] method.
+///
+/// [`get_masked_mut`]: crate::GetManyMutExt::get_masked_mut
+pub struct MaskedIterMut<'a, T, B> {
+    ptr: NonNull<T>,
+    mask: B,
+    marker: PhantomData<&'a mut [T]>,
+}
+
+// SAFETY: `MaskedIterMut` behaves like `&'a mut [T]`.
+unsafe impl<'a, T: Send, B: Send> Send for MaskedIterMut<'a, T, B> {}
+unsafe impl<'a, T: Sync, B: Sync> Sync for MaskedIterMut<'a, T, B> {}
+
+impl<'a, T, B: BitMask> MaskedIterMut<'a, T, B> {
+    pub(crate) fn new(slice: &'a mut [T], mask: B) -> Option<Self> {
+        match mask.highest() {
+            Some(highest) if highest >= slice.len() => None,
+            _ => Some(Self {
+                ptr: NonNull::from(slice).cast(),
+                mask,
+                marker: PhantomData,
+            }),
+        }
+    }
+}
+
+impl<'a, T, B: BitMask> Iterator for MaskedIterMut<'a, T, B> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.mask.take_lowest()?;
+        // SAFETY: All set bits are in bounds, and each is only yielded once.
+        unsafe { Some(&mut *self.ptr.as_ptr().add(index)) }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.mask.count(), Some(self.mask.count()))
+    }
+}
+
+impl<'a, T, B: BitMask> DoubleEndedIterator for MaskedIterMut<'a, T, B> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.mask.take_highest()?;
+        // SAFETY: All set bits are in bounds, and each is only yielded once.
+        unsafe { Some(&mut *self.ptr.as_ptr().add(index)) }
+    }
+}
+
+impl<'a, T, B: BitMask> ExactSizeIterator for MaskedIterMut<'a, T, B> {}
+
+/// An iterator over mutable references to every element of a slice except
+/// those at some excluded indices, in ascending order.
+///
+/// This `struct` is created by the [`get_many_mut_and_rest`] and
+/// [`get_all_except_mut`] methods.
+///
+/// [`get_many_mut_and_rest`]: crate::GetManyMutExt::get_many_mut_and_rest
+/// [`get_all_except_mut`]: crate::GetManyMutExt::get_all_except_mut
+pub struct RestMut<'a, T, const N: usize> {
+    ptr: NonNull<T>,
+    len: usize,
+    /// The next index to yield, unless it is excluded.
+    next: usize,
+    /// The excluded indices, in ascending order.
+    excluded: [usize; N],
+    /// The number of excluded indices before `next`.
+    skipped: usize,
+    marker: PhantomData<&'a mut [T]>,
+}
+
+// SAFETY: `RestMut` behaves like `&'a mut [T]`.
+unsafe impl<'a, T: Send, const N: usize> Send for RestMut<'a, T, N> {}
+unsafe impl<'a, T: Sync, const N: usize> Sync for RestMut<'a, T, N> {}
+
+impl<'a, T, const N: usize> RestMut<'a, T, N> {
+    /// # Safety
+    ///
+    /// `ptr` and `len` must describe a slice that is valid for `'a`, and
+    /// `excluded` must be distinct and less than `len`. Only the elements that
+    /// are not excluded may be accessed while this is alive.
+    pub(crate) unsafe fn new(
+        ptr: NonNull<T>,
+        len: usize,
+        mut excluded: [usize; N],
+    ) -> Self {
+        excluded.sort_unstable();
+        Self { ptr, len, next: 0, excluded, skipped: 0, marker: PhantomData }
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for RestMut<'a, T, N> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.next < self.len {
+            let idx = self.next;
+            self.next += 1;
+            if self.excluded.get(self.skipped) == Some(&idx) {
+                self.skipped += 1;
+            } else {
+                // SAFETY: `idx` is in bounds, not excluded, and only yielded
+                // once.
+                return unsafe { Some(&mut *self.ptr.as_ptr().add(idx)) };
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.len - self.next) - (N - self.skipped);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T, const N: usize> ExactSizeIterator for RestMut<'a, T, N> {}
+
+/// An iterator over mutable references to every `stride`th element of a
+/// slice, e.g. one column of a row-major matrix.
+///
+/// This `struct` is created by the [`get_columns_mut`] method.
+///
+/// [`get_columns_mut`]: crate::GetManyMutExt::get_columns_mut
+pub struct StridedMut<'a, T> {
+    ptr: NonNull<T>,
+    stride: usize,
+    /// The index of the next element from the front, in units of `stride`.
+    front: usize,
+    /// One past the index of the next element from the back, in units of
+    /// `stride`.
+    back: usize,
+    marker: PhantomData<&'a mut T>,
+}
+
+// SAFETY: `StridedMut` behaves like `slice::IterMut<'a, T>`.
+unsafe impl<'a, T: Send> Send for StridedMut<'a, T> {}
+unsafe impl<'a, T: Sync> Sync for StridedMut<'a, T> {}
+
+impl<'a, T> StridedMut<'a, T> {
+    /// # Safety
+    ///
+    /// For every `i < count`, `ptr.add(i * stride)` must be valid for `'a`,
+    /// and only those elements may be accessed through this.
+    pub(crate) unsafe fn new(
+        ptr: NonNull<T>,
+        stride: usize,
+        count: usize,
+    ) -> Self {
+        Self { ptr, stride, front: 0, back: count, marker: PhantomData }
+    }
+}
+
+impl<'a, T> Iterator for StridedMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            let idx = self.front * self.stride;
+            self.front += 1;
+            // SAFETY: `idx` is in bounds, and only yielded once.
+            unsafe { Some(&mut *self.ptr.as_ptr().add(idx)) }
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for StridedMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+            let idx = self.back * self.stride;
+            // SAFETY: `idx` is in bounds, and only yielded once.
+            unsafe { Some(&mut *self.ptr.as_ptr().add(idx)) }
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for StridedMut<'a, T> {}
@@ -1,19 +1,246 @@
 #![no_std]
 #![deny(unsafe_op_in_unsafe_fn)]
+#![cfg_attr(
+    feature = "nightly",
+    feature(adt_const_params, unsized_const_params),
+    allow(incomplete_features)
+)]
 //! Stable polyfill for [`slice::get_many_mut`].
 //!
 //! Mostly copied from Rust stdlib core/src/slice.rs
+//!
+//! # Other containers
+//!
+//! The methods of [`GetManyMutExt`] are available on any type that
+//! dereferences to a slice, like `Vec<T>`, `Box<[T]>`, `bytes::BytesMut`, or
+//! `memmap2::MmapMut`, through auto-deref. For example, several header fields
+//! of a buffer can be patched at once:
+//!
+//! ```
+//! use get_many_mut::GetManyMutExt;
+//!
+//! let mut packet: Vec<u8> = vec![0; 8];
+//! let [len, flags] = packet.get_many_ranges_mut([0..2, 4..5]).unwrap();
+//! len.copy_from_slice(&8u16.to_be_bytes());
+//! flags[0] = 0x80;
+//! assert_eq!(packet, [0, 8, 0, 0, 0x80, 0, 0, 0]);
+//! ```
+//!
+//! Generic code that should accept all of these, including array types from
+//! other crates like `generic_array::GenericArray`, can bound on
+//! `AsMut<[T]>` and call the methods on the slice:
+//!
+//! ```
+//! use get_many_mut::GetManyMutExt;
+//!
+//! fn swap_ends<A: AsMut<[u8]>>(mut data: A) -> A {
+//!     let slice = data.as_mut();
+//!     let last = slice.len().saturating_sub(1);
+//!     if let Ok([first, last]) = slice.get_many_mut([0, last]) {
+//!         core::mem::swap(first, last);
+//!     }
+//!     data
+//! }
+//!
+//! assert_eq!(swap_ends([1, 2, 3]), [3, 2, 1]);
+//! assert_eq!(swap_ends(vec![1, 2]), [2, 1]);
+//! assert_eq!(swap_ends(vec![1]), [1]);
+//! ```
+//!
+//! # Integrations that are not provided
+//!
+//! This crate has no dependencies, so there are no features for the types of
+//! other crates:
+//!
+//! - `bitvec`: there is no `get_many_mut` on `BitSlice` returning `BitRef`
+//!   proxies. Single bits can't be borrowed through these methods, but the
+//!   words of the underlying storage slice can.
+//! - `rayon`: there is no `par_scope_many_mut` that runs a closure on disjoint
+//!   groups in parallel.
+//!   [`get_disjoint_groups_mut`](GetManyMutExt::get_disjoint_groups_mut) only
+//!   validates the groups and returns their references. Each group can then be
+//!   handed to its own thread or rayon task.
+//! - `generic-array`: `GenericArray` does not implement [`GetManyMutExt`], so
+//!   it does not satisfy the same bounds as arrays. Bound on `AsMut<[T]>`
+//!   instead, as shown above.
+//! - `ndarray`: there is no `get_many_mut` on `ArrayViewMut2` or
+//!   `ArrayViewMutD`. Only the slice-level building block
+//!   [`get_many_mut_nd_strided`](GetManyMutExt::get_many_mut_nd_strided) is
+//!   provided. The caller passes the array's shape, strides and the offset of
+//!   its first element.
+//!
+//! # Checked preconditions
+//!
+//! The unsafe methods like
+//! [`get_many_unchecked_mut`](GetManyMutExt::get_many_unchecked_mut) check
+//! their safety preconditions when debug assertions are enabled, and panic if
+//! they are violated. Release builds do no checks.
+//!
+//! With the `checked-unsafe` feature, the preconditions are always checked,
+//! even in release builds, and a violation aborts the process instead of
+//! unwinding. This is meant for fuzzing and sanitizer runs of downstream
+//! crates.
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
-use core::{fmt, mem};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{fmt, hint, mem, ops::Range, ptr, ptr::NonNull, slice};
+
+mod all;
+mod branded;
+mod cell;
+mod check;
+#[cfg(feature = "alloc")]
+mod cursor;
+mod disjoint_indices;
+mod grid;
+#[cfg(feature = "std")]
+mod hash_map;
+mod index;
+mod iter;
+mod macros;
+#[cfg(feature = "nightly")]
+mod nightly;
+mod pin;
+mod raw;
+#[cfg(feature = "alloc")]
+mod rc;
+mod string;
+mod uninit;
+#[cfg(feature = "alloc")]
+mod vec;
+mod view;
+mod zip;
+
+pub use all::GetAllMutExt;
+pub use branded::{BrandedIndex, BrandedIndices, BrandedSlice, with_branded};
+pub use cell::SliceCell;
+pub use check::{indices_are_disjoint, sorted_indices_are_disjoint};
+#[cfg(feature = "alloc")]
+pub use cursor::{DisjointCursor, IterManyMut};
+pub use disjoint_indices::{DisjointIndices, TrustedDisjointIndices};
+pub use grid::GetManyMut2dExt;
+#[cfg(feature = "std")]
+pub use hash_map::{EntryMany, EntryManyExt};
+pub use index::{IndexType, RelIndex};
+pub use iter::{BitMask, MaskedIterMut, RestMut, SelectMaskMut, StridedMut};
+#[cfg(feature = "nightly")]
+pub use nightly::GetManyConstMutExt;
+pub use pin::GetManyPinMutExt;
+pub use raw::{GetManyRawExt, RawSliceAccess};
+#[cfg(feature = "alloc")]
+pub use rc::{GetManyMutRcError, GetManyMutRcExt};
+pub use string::GetManyStrMutExt;
+pub use uninit::WriteManyExt;
+#[cfg(feature = "alloc")]
+pub use vec::{DrainMany, GetManyMutVecExt};
+pub use view::DisjointSliceView;
+pub use zip::ZipGetManyMutExt;
+
+#[cfg(all(
+    feature = "simd",
+    target_arch = "x86_64",
+    target_pointer_width = "64"
+))]
+mod simd;
+#[cfg(all(
+    feature = "simd",
+    target_arch = "x86_64",
+    target_pointer_width = "64"
+))]
+use simd::get_many_check_valid_pairwise;
+
+/// Checks the safety precondition of an unchecked method, if debug assertions
+/// or the `checked-unsafe` feature are enabled.
+///
+/// Like `core`'s internal `assert_unsafe_precondition!`, this turns misuse into
+/// a panic in debug builds, and does nothing in release builds. With the
+/// `checked-unsafe` feature, misuse is always checked, and aborts instead.
+macro_rules! assert_unsafe_precondition {
+    ($check:expr, $message:literal $(,)?) => {
+        if cfg!(any(debug_assertions, feature = "checked-unsafe")) {
+            let satisfied: bool = $check;
+            if !satisfied {
+                $crate::precondition_violated($message);
+            }
+        }
+    };
+}
+
+#[cold]
+#[inline(never)]
+fn precondition_violated(message: &str) -> ! {
+    #[cfg(all(feature = "checked-unsafe", feature = "std"))]
+    {
+        std::eprintln!("unsafe precondition(s) violated: {}", message);
+        std::process::abort()
+    }
+    // NB: Without `std` there is no portable way to abort, but panicking
+    // while already panicking always aborts, so the guard does that if the
+    // first panic unwinds.
+    #[cfg(all(feature = "checked-unsafe", not(feature = "std")))]
+    let _guard = {
+        struct AbortOnUnwind;
+        impl Drop for AbortOnUnwind {
+            fn drop(&mut self) {
+                panic!("aborting");
+            }
+        }
+        AbortOnUnwind
+    };
+    #[cfg(not(all(feature = "checked-unsafe", feature = "std")))]
+    panic!("unsafe precondition(s) violated: {}", message)
+}
+
+/// Above this many indices, [`get_many_check_valid`] sorts a copy of the
+/// indices instead of comparing every pair.
+const SORTED_CHECK_THRESHOLD: usize = 32;
+
+/// This checks every index against each other, and against `len`.
+///
+/// For small `N` this does every pairwise comparison, see
+/// [`get_many_check_valid_pairwise`]. For larger `N` it sorts a copy of
+/// `indices` first, doing `O(N log N)` operations.
+fn get_many_check_valid<const N: usize>(
+    indices: &[usize; N],
+    len: usize,
+) -> bool {
+    // NB: `N` is a constant, so only one of these branches will remain.
+    // The common cases of two and three indices are spelled out, so that they
+    // compile to a minimal branch-free sequence regardless of the loop
+    // optimizations in `get_many_check_valid_pairwise`.
+    if N == 2 {
+        let (a, b) = (indices[0], indices[1]);
+        (a < len) & (b < len) & (a != b)
+    } else if N == 3 {
+        let (a, b, c) = (indices[0], indices[1], indices[2]);
+        (a < len) & (b < len) & (c < len) & (a != b) & (a != c) & (b != c)
+    } else if N > SORTED_CHECK_THRESHOLD {
+        let mut sorted = *indices;
+        sorted.sort_unstable();
+        get_many_check_sorted(&sorted, len)
+    } else {
+        get_many_check_valid_pairwise(indices, len)
+    }
+}
 
 /// This checks every index against each other, and against `len`.
 ///
 /// This will do `binomial(N + 1, 2) = N * (N + 1) / 2 = 0, 1, 3, 6, 10, ..`
 /// comparison operations.
-fn get_many_check_valid<const N: usize>(
+///
+/// With the `simd` feature on x86_64, this is replaced by a vectorized
+/// implementation.
+#[cfg(not(all(
+    feature = "simd",
+    target_arch = "x86_64",
+    target_pointer_width = "64"
+)))]
+fn get_many_check_valid_pairwise<const N: usize>(
     indices: &[usize; N],
     len: usize,
 ) -> bool {
@@ -29,9 +256,158 @@ fn get_many_check_valid<const N: usize>(
     valid
 }
 
+/// This checks every index against `len`, but not against each other.
+///
+/// This will do `N` comparison operations.
+fn get_many_check_in_bounds<const N: usize>(
+    indices: &[usize; N],
+    len: usize,
+) -> bool {
+    let mut valid = true;
+    for &idx in indices {
+        valid &= idx < len;
+    }
+    valid
+}
+
+/// This checks every index against each other, but not against any length.
+///
+/// Like [`get_many_check_valid`], this sorts a copy of `indices` for large
+/// `N`.
+fn get_many_check_disjoint<const N: usize>(indices: &[usize; N]) -> bool {
+    let mut valid = true;
+    if N > SORTED_CHECK_THRESHOLD {
+        let mut sorted = *indices;
+        sorted.sort_unstable();
+        for pair in sorted.windows(2) {
+            valid &= pair[0] != pair[1];
+        }
+    } else {
+        for (i, &idx) in indices.iter().enumerate() {
+            for &idx2 in &indices[..i] {
+                valid &= idx != idx2;
+            }
+        }
+    }
+    valid
+}
+
+/// This checks that `indices` is strictly ascending, and that the last index
+/// is less than `len`.
+///
+/// This will do `N` comparison operations.
+fn get_many_check_sorted<const N: usize>(
+    indices: &[usize; N],
+    len: usize,
+) -> bool {
+    let mut valid = true;
+    for pair in indices.windows(2) {
+        valid &= pair[0] < pair[1];
+    }
+    match indices.last() {
+        Some(&last) => valid & (last < len),
+        None => valid,
+    }
+}
+
+/// This checks every window `start..start + K` against each other, and
+/// against `len`.
+///
+/// This will do the same number of comparisons as
+/// [`get_many_check_valid_pairwise`].
+fn get_many_check_valid_arrays<const K: usize, const N: usize>(
+    starts: &[usize; N],
+    len: usize,
+) -> bool {
+    let mut valid = true;
+    for (i, &start) in starts.iter().enumerate() {
+        valid &= (start <= len) & (len.wrapping_sub(start) >= K);
+        for &start2 in &starts[..i] {
+            valid &= (start >= start2.saturating_add(K))
+                | (start2 >= start.saturating_add(K));
+        }
+    }
+    valid
+}
+
+/// This checks both indices of every pair against each other, against the
+/// indices of every other pair, and against `len`.
+///
+/// This will do the same number of comparisons as
+/// [`get_many_check_valid_pairwise`] on `2 * N` indices.
+fn get_many_check_valid_pairs<const N: usize>(
+    pairs: &[(usize, usize); N],
+    len: usize,
+) -> bool {
+    let mut valid = true;
+    for (i, &(a, b)) in pairs.iter().enumerate() {
+        valid &= (a < len) & (b < len) & (a != b);
+        for &(a2, b2) in &pairs[..i] {
+            valid &= (a != a2) & (a != b2) & (b != a2) & (b != b2);
+        }
+    }
+    valid
+}
+
+/// This checks every range against each other, and against `len`.
+///
+/// This will do the same number of comparisons as
+/// [`get_many_check_valid_pairwise`].
+fn get_many_check_valid_ranges<const N: usize>(
+    ranges: &[Range<usize>; N],
+    len: usize,
+) -> bool {
+    let mut valid = true;
+    for (i, range) in ranges.iter().enumerate() {
+        valid &= (range.start <= range.end) & (range.end <= len);
+        for range2 in &ranges[..i] {
+            valid &= (range.end <= range2.start) | (range.start >= range2.end);
+        }
+    }
+    valid
+}
+
 /// Extension trait for [`get_many_mut`](GetManyMutExt::get_many_mut).
+///
+/// Only [`as_slice`](GetManyMutExt::as_slice) and
+/// [`as_mut_slice`](GetManyMutExt::as_mut_slice) have to be implemented. Every
+/// other method is provided, and works on the slice returned by one of them.
+///
+/// ```
+/// use get_many_mut::GetManyMutExt;
+///
+/// struct Stack {
+///     items: Vec<u32>,
+/// }
+///
+/// // SAFETY: No provided methods are overridden.
+/// unsafe impl GetManyMutExt for Stack {
+///     type Element = u32;
+///     fn as_slice(&self) -> &[u32] {
+///         &self.items
+///     }
+///     fn as_mut_slice(&mut self) -> &mut [u32] {
+///         &mut self.items
+///     }
+/// }
+///
+/// let mut stack = Stack { items: vec![1, 2, 3] };
+/// let [a, b] = stack.get_many_mut([0, 2]).unwrap();
+/// core::mem::swap(a, b);
+/// assert_eq!(stack.items, [3, 2, 1]);
+/// ```
+///
+/// # Safety
+///
+/// Implementations that override a provided method must uphold its
+/// documented guarantees, e.g. that the references returned by
+/// [`get_many_mut`](GetManyMutExt::get_many_mut) are to distinct elements.
 pub unsafe trait GetManyMutExt {
     type Element;
+    /// Returns the elements as a slice.
+    fn as_slice(&self) -> &[Self::Element];
+    /// Returns the elements as a mutable slice.
+    fn as_mut_slice(&mut self) -> &mut [Self::Element];
     /// Returns mutable references to many indices at once.
     ///
     /// Returns an error if any index is out-of-bounds, or if the same index was
@@ -55,10 +431,40 @@ pub unsafe trait GetManyMutExt {
     /// let v = &mut [1, 2, 3];
     /// v.get_many_mut([0, 2, 0]).unwrap();
     /// ```
+    ///
+    /// Large numbers of indices are validated in `O(N log N)` time.
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutExt;
+    ///
+    /// let v = &mut [0; 100];
+    /// let mut indices = [0; 64];
+    /// for (i, idx) in indices.iter_mut().enumerate() {
+    ///     *idx = 99 - i;
+    /// }
+    /// assert!(v.get_many_mut(indices).is_ok());
+    ///
+    /// indices[63] = 99;
+    /// assert!(v.get_many_mut(indices).is_err());
+    ///
+    /// indices[63] = 100;
+    /// assert!(v.get_many_mut(indices).is_err());
+    /// ```
     fn get_many_mut<const N: usize>(
         &mut self,
         indices: [usize; N],
-    ) -> Result<[&mut Self::Element; N], GetManyMutError<N>>;
+    ) -> Result<[&mut Self::Element; N], GetManyMutError<N>> {
+        let this = self.as_mut_slice();
+        if get_many_check_valid(&indices, this.len()) {
+            unsafe {
+                Ok(<[Self::Element] as GetManyMutExt>::get_many_unchecked_mut(
+                    this, indices,
+                ))
+            }
+        } else {
+            Err(GetManyMutError::new())
+        }
+    }
     /// Returns mutable references to many indices at once, without doing any
     /// checks.
     ///
@@ -70,6 +476,9 @@ pub unsafe trait GetManyMutExt {
     /// *[undefined behavior]* even if the resulting references are not
     /// used.
     ///
+    /// This is checked in some builds, see [checked
+    /// preconditions](crate#checked-preconditions).
+    ///
     /// # Examples
     ///
     /// ```
@@ -87,39 +496,46 @@ pub unsafe trait GetManyMutExt {
     ///
     /// [`get_many_mut`]: GetManyMutExt::get_many_mut
     /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    // NB: See the comment below for why this isn't an iterator.
+    #[allow(clippy::needless_range_loop)]
     unsafe fn get_many_unchecked_mut<const N: usize>(
         &mut self,
         indices: [usize; N],
-    ) -> [&mut Self::Element; N];
-}
-
-unsafe impl<T> GetManyMutExt for [T] {
-    type Element = T;
-    fn get_many_mut<const N: usize>(
-        &mut self,
-        indices: [usize; N],
-    ) -> Result<[&mut Self::Element; N], GetManyMutError<N>> {
-        if get_many_check_valid(&indices, self.len()) {
-            unsafe {
-                Ok(<Self as GetManyMutExt>::get_many_unchecked_mut(
-                    self, indices,
-                ))
-            }
-        } else {
-            Err(GetManyMutError)
-        }
-    }
-    unsafe fn get_many_unchecked_mut<const N: usize>(
-        &mut self,
-        indices: [usize; N],
-    ) -> [&mut T; N] {
+    ) -> [&mut Self::Element; N] {
+        let this = self.as_mut_slice();
         // NB: This implementation is written as it is because any variation of
         // `indices.map(|i| self.get_unchecked_mut(i))` would make miri unhappy,
         // or generate worse code otherwise. This is also why we need to go
         // through a raw pointer here.
-        let ptr: *mut T = self.as_mut_ptr();
-        let mut arr: mem::MaybeUninit<[&mut T; N]> = mem::MaybeUninit::uninit();
-        let arr_ptr: *mut *mut T = arr.as_mut_ptr().cast();
+        assert_unsafe_precondition!(
+            get_many_check_valid(&indices, this.len()),
+            "get_many_unchecked_mut requires distinct, in-bounds indices",
+        );
+        let ptr: *mut Self::Element = this.as_mut_ptr();
+
+        // SAFETY: We expect `indices` to contain disjunct values that are
+        // in bounds of `self`.
+        unsafe {
+            let elem = |i: usize| ptr.add(indices[i]);
+            // NB: `N` is a constant, so only one of these branches will
+            // remain. The common cases of two and three indices build the
+            // array directly instead of element by element through
+            // `MaybeUninit`, so that they don't depend on the loop below being
+            // unrolled. The `transmute_copy`s only change `2` or `3` to `N`.
+            if N == 2 {
+                let arr: [&mut Self::Element; 2] =
+                    [&mut *elem(0), &mut *elem(1)];
+                return mem::transmute_copy(&arr);
+            } else if N == 3 {
+                let arr: [&mut Self::Element; 3] =
+                    [&mut *elem(0), &mut *elem(1), &mut *elem(2)];
+                return mem::transmute_copy(&arr);
+            }
+        }
+
+        let mut arr: mem::MaybeUninit<[&mut Self::Element; N]> =
+            mem::MaybeUninit::uninit();
+        let arr_ptr: *mut *mut Self::Element = arr.as_mut_ptr().cast();
 
         // SAFETY: We expect `indices` to contain disjunct values that are
         // in bounds of `self`.
@@ -131,45 +547,1645 @@ unsafe impl<T> GetManyMutExt for [T] {
             arr.assume_init()
         }
     }
-}
-
-unsafe impl<T, const M: usize> GetManyMutExt for [T; M] {
-    type Element = T;
-    fn get_many_mut<const N: usize>(
+    /// Returns mutable references to many indices of any [`IndexType`] at
+    /// once.
+    ///
+    /// This is [`get_many_mut`] for indices that are stored as other integer
+    /// types, such as `u16` or `u32`, or as index newtypes, so that they do not
+    /// need to be converted first.
+    ///
+    /// Returns an error if any index is out-of-bounds or cannot be converted to
+    /// `usize`, or if the same index was passed more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutExt;
+    ///
+    /// let v = &mut [1, 2, 3];
+    /// let indices: [u16; 2] = [2, 0];
+    /// let [a, b] = v.get_many_typed_mut(indices).unwrap();
+    /// core::mem::swap(a, b);
+    /// assert_eq!(v, &[3, 2, 1]);
+    ///
+    /// assert!(v.get_many_typed_mut([-1i32]).is_err());
+    /// ```
+    ///
+    /// [`get_many_mut`]: GetManyMutExt::get_many_mut
+    fn get_many_typed_mut<I: IndexType, const N: usize>(
+        &mut self,
+        indices: [I; N],
+    ) -> Result<[&mut Self::Element; N], GetManyMutError<N>> {
+        match index::to_usize_indices(indices) {
+            Some(indices) => self.get_many_mut(indices),
+            None => Err(GetManyMutError::new()),
+        }
+    }
+    /// Returns mutable references to many indices of any [`IndexType`] at
+    /// once, without doing any checks.
+    ///
+    /// For a safe alternative see [`get_many_typed_mut`].
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with overlapping or out-of-bounds indices, or
+    /// indices that cannot be converted to `usize`, is *[undefined behavior]*
+    /// even if the resulting references are not used.
+    ///
+    /// This is checked in some builds, see [checked
+    /// preconditions](crate#checked-preconditions).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutExt;
+    ///
+    /// let x = &mut [1, 2, 4];
+    /// let [a, b] = unsafe { x.get_many_unchecked_typed_mut([0u8, 2]) };
+    /// core::mem::swap(a, b);
+    /// assert_eq!(x, &[4, 2, 1]);
+    /// ```
+    ///
+    /// [`get_many_typed_mut`]: GetManyMutExt::get_many_typed_mut
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    unsafe fn get_many_unchecked_typed_mut<I: IndexType, const N: usize>(
+        &mut self,
+        indices: [I; N],
+    ) -> [&mut Self::Element; N] {
+        assert_unsafe_precondition!(
+            index::to_usize_indices(indices).is_some(),
+            "get_many_unchecked_typed_mut requires indices that can be \
+             converted to usize",
+        );
+        let indices = match index::to_usize_indices(indices) {
+            Some(indices) => indices,
+            // SAFETY: The caller guarantees that the indices can be converted.
+            None => unsafe { hint::unreachable_unchecked() },
+        };
+        // SAFETY: The caller guarantees that the indices are valid.
+        unsafe { self.get_many_unchecked_mut(indices) }
+    }
+    /// Returns mutable references to many strictly ascending indices at once.
+    ///
+    /// This is equivalent to [`get_many_mut`], but validation only takes `N`
+    /// comparisons instead of `N * (N + 1) / 2`.
+    ///
+    /// Returns an error if the indices are not strictly ascending, or if any
+    /// index is out-of-bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutExt;
+    ///
+    /// let v = &mut [1, 2, 3, 4];
+    /// if let Ok([a, b, c]) = v.get_many_mut_sorted([0, 1, 3]) {
+    ///     *a = 413;
+    ///     *b = 612;
+    ///     *c = 1025;
+    /// }
+    /// assert_eq!(v, &[413, 612, 3, 1025]);
+    ///
+    /// assert!(v.get_many_mut_sorted([1, 0]).is_err());
+    /// assert!(v.get_many_mut_sorted([1, 1]).is_err());
+    /// assert!(v.get_many_mut_sorted([1, 4]).is_err());
+    /// ```
+    ///
+    /// [`get_many_mut`]: GetManyMutExt::get_many_mut
+    fn get_many_mut_sorted<const N: usize>(
         &mut self,
         indices: [usize; N],
     ) -> Result<[&mut Self::Element; N], GetManyMutError<N>> {
-        <[T] as GetManyMutExt>::get_many_mut(self, indices)
+        let this = self.as_mut_slice();
+        if get_many_check_sorted(&indices, this.len()) {
+            unsafe {
+                Ok(<[Self::Element] as GetManyMutExt>::get_many_unchecked_mut(
+                    this, indices,
+                ))
+            }
+        } else {
+            Err(GetManyMutError::new())
+        }
     }
-    unsafe fn get_many_unchecked_mut<const N: usize>(
+    /// Returns mutable references to many strictly ascending indices at once,
+    /// without checking that they are ascending.
+    ///
+    /// Only the last index is checked to be in bounds, so validation is
+    /// constant-time.
+    ///
+    /// For a safe alternative see [`get_many_mut_sorted`].
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with indices that are not strictly ascending is
+    /// *[undefined behavior]* even if the resulting references are not
+    /// used.
+    ///
+    /// This is checked in some builds, see [checked
+    /// preconditions](crate#checked-preconditions).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutExt;
+    ///
+    /// let x = &mut [1, 2, 4];
+    ///
+    /// unsafe {
+    ///     let [a, b] = x.get_many_mut_assume_sorted([0, 2]).unwrap();
+    ///     *a *= 10;
+    ///     *b *= 100;
+    ///     assert!(x.get_many_mut_assume_sorted([0, 3]).is_err());
+    /// }
+    /// assert_eq!(x, &[10, 2, 400]);
+    /// ```
+    ///
+    /// [`get_many_mut_sorted`]: GetManyMutExt::get_many_mut_sorted
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    unsafe fn get_many_mut_assume_sorted<const N: usize>(
         &mut self,
         indices: [usize; N],
-    ) -> [&mut T; N] {
-        unsafe { <[T] as GetManyMutExt>::get_many_unchecked_mut(self, indices) }
+    ) -> Result<[&mut Self::Element; N], GetManyMutError<N>> {
+        let this = self.as_mut_slice();
+        assert_unsafe_precondition!(
+            indices.windows(2).all(|pair| pair[0] < pair[1]),
+            "get_many_mut_assume_sorted requires strictly ascending indices",
+        );
+        match indices.last() {
+            Some(&last) if last >= this.len() => Err(GetManyMutError::new()),
+            // SAFETY: The caller guarantees that `indices` is strictly
+            // ascending, so if the last index is in bounds, all are.
+            _ => unsafe {
+                Ok(<[Self::Element] as GetManyMutExt>::get_many_unchecked_mut(
+                    this, indices,
+                ))
+            },
+        }
     }
-}
-
-/// The error type returned by
-/// [`get_many_mut<N>`][`GetManyMutExt::get_many_mut`].
-///
-/// It indicates one of two possible errors:
-/// - An index is out-of-bounds.
-/// - The same index appeared multiple times in the array.
-///
-/// # Examples
-///
-/// ```
-/// use get_many_mut::GetManyMutExt;
-///
-/// let v = &mut [1, 2, 3];
-/// assert!(v.get_many_mut([0, 999]).is_err());
-/// assert!(v.get_many_mut([1, 1]).is_err());
-/// ```
-// NB: The N here is there to be forward-compatible with adding more details
-// to the error type at a later point
-#[non_exhaustive]
-pub struct GetManyMutError<const N: usize>;
-
+    /// Returns mutable references to many indices at once, using indices that
+    /// have already been validated.
+    ///
+    /// The indices can be a [`DisjointIndices`], or any other type
+    /// implementing [`TrustedDisjointIndices`].
+    ///
+    /// Returns an error if `self` is shorter than [`indices.min_len()`].
+    ///
+    /// See [`DisjointIndices`] for an example.
+    ///
+    /// [`indices.min_len()`]: TrustedDisjointIndices::min_len
+    fn get_many_mut_with<D, const N: usize>(
+        &mut self,
+        indices: &D,
+    ) -> Result<[&mut Self::Element; N], GetManyMutError<N>>
+    where
+        D: TrustedDisjointIndices<N> + ?Sized,
+    {
+        let this = self.as_mut_slice();
+        if indices.min_len() <= this.len() {
+            // SAFETY: `indices` are distinct and less than `min_len`.
+            unsafe {
+                Ok(<[Self::Element] as GetManyMutExt>::get_many_unchecked_mut(
+                    this,
+                    indices.indices(),
+                ))
+            }
+        } else {
+            Err(GetManyMutError::new())
+        }
+    }
+    /// Divides one mutable slice into `N + 1` at `N` ascending positions.
+    ///
+    /// The array contains the subslices ending at each of `mids`, and the
+    /// remaining subslice contains all elements after the last of `mids`.
+    /// This is equivalent to calling [`split_at_mut`] `N` times.
+    ///
+    /// Returns an error if `mids` is not in ascending order, or if any
+    /// position is greater than the length of the slice. Positions may be
+    /// repeated, which results in empty subslices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutExt;
+    ///
+    /// let v = &mut [1, 2, 3, 4, 5, 6];
+    /// let ([a, b, c], rest) = v.split_at_many_mut([1, 3, 3]).unwrap();
+    /// assert_eq!(a, &[1]);
+    /// assert_eq!(b, &[2, 3]);
+    /// assert_eq!(c, &[]);
+    /// assert_eq!(rest, &[4, 5, 6]);
+    ///
+    /// assert!(v.split_at_many_mut([3, 1]).is_err());
+    /// assert!(v.split_at_many_mut([1, 7]).is_err());
+    /// ```
+    ///
+    /// [`split_at_mut`]: slice::split_at_mut
+    #[allow(clippy::type_complexity)]
+    fn split_at_many_mut<const N: usize>(
+        &mut self,
+        mids: [usize; N],
+    ) -> Result<
+        ([&mut [Self::Element]; N], &mut [Self::Element]),
+        GetManyMutError<N>,
+    > {
+        let this = self.as_mut_slice();
+        let len = this.len();
+        let mut valid = true;
+        let mut start = 0;
+        for &mid in &mids {
+            valid &= start <= mid;
+            start = mid;
+        }
+        if !valid || start > len {
+            return Err(GetManyMutError::new());
+        }
+
+        let ptr: *mut Self::Element = this.as_mut_ptr();
+        let mut arr: mem::MaybeUninit<[&mut [Self::Element]; N]> =
+            mem::MaybeUninit::uninit();
+        let arr_ptr: *mut &mut [Self::Element] = arr.as_mut_ptr().cast();
+
+        // SAFETY: `mids` is ascending and in bounds, so the subslices are
+        // disjoint and in bounds.
+        unsafe {
+            let mut start = 0;
+            for (i, &mid) in mids.iter().enumerate() {
+                *arr_ptr.add(i) =
+                    slice::from_raw_parts_mut(ptr.add(start), mid - start);
+                start = mid;
+            }
+            let rest = slice::from_raw_parts_mut(ptr.add(start), len - start);
+            Ok((arr.assume_init(), rest))
+        }
+    }
+    /// Returns mutable references to many fixed-size windows at once.
+    ///
+    /// Each window contains the `K` elements beginning at the corresponding
+    /// index in `starts`.
+    ///
+    /// Returns an error if any window is out-of-bounds, or if any two windows
+    /// overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutExt;
+    ///
+    /// let v = &mut [1, 2, 3, 4, 5, 6];
+    /// let [a, b] = v.get_many_arrays_mut::<2, 2>([4, 1]).unwrap();
+    /// core::mem::swap(a, b);
+    /// assert_eq!(v, &[1, 5, 6, 4, 2, 3]);
+    ///
+    /// assert!(v.get_many_arrays_mut::<2, 2>([1, 2]).is_err());
+    /// assert!(v.get_many_arrays_mut::<2, 2>([0, 5]).is_err());
+    /// ```
+    fn get_many_arrays_mut<const K: usize, const N: usize>(
+        &mut self,
+        starts: [usize; N],
+    ) -> Result<[&mut [Self::Element; K]; N], GetManyMutError<N>> {
+        let this = self.as_mut_slice();
+        if !get_many_check_valid_arrays::<K, N>(&starts, this.len()) {
+            return Err(GetManyMutError::new());
+        }
+
+        let ptr: *mut Self::Element = this.as_mut_ptr();
+        let mut arr: mem::MaybeUninit<[&mut [Self::Element; K]; N]> =
+            mem::MaybeUninit::uninit();
+        let arr_ptr: *mut *mut [Self::Element; K] = arr.as_mut_ptr().cast();
+
+        // SAFETY: The windows are disjoint and in bounds.
+        unsafe {
+            for (i, &start) in starts.iter().enumerate() {
+                *arr_ptr.add(i) = ptr.add(start).cast();
+            }
+            Ok(arr.assume_init())
+        }
+    }
+    /// Returns an iterator over mutable references to the elements at each
+    /// index of `indices`.
+    ///
+    /// Each item is an error if its index is out-of-bounds, or if the same
+    /// index was already yielded by this iterator. Iteration continues after
+    /// an error.
+    ///
+    /// This keeps track of which elements have been yielded using one bit per
+    /// element, see [`DisjointCursor`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutExt;
+    ///
+    /// let v = &mut [1, 2, 3, 4];
+    /// let mut refs = v.iter_many_mut((0..4).rev().step_by(2));
+    /// let a = refs.next().unwrap().unwrap();
+    /// let b = refs.next().unwrap().unwrap();
+    /// assert!(refs.next().is_none());
+    /// core::mem::swap(a, b);
+    /// assert_eq!(v, &[1, 4, 3, 2]);
+    ///
+    /// let results: Vec<_> = v.iter_many_mut([0, 4, 0]).collect();
+    /// assert!(results[0].is_ok());
+    /// assert!(results[1].is_err());
+    /// assert!(results[2].is_err());
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn iter_many_mut<I>(
+        &mut self,
+        indices: I,
+    ) -> IterManyMut<'_, Self::Element, I::IntoIter>
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        let this = self.as_mut_slice();
+        IterManyMut::new(this, indices.into_iter())
+    }
+    /// Returns an iterator over mutable references to the elements for which
+    /// `mask` is `true`.
+    ///
+    /// If `mask` and `self` have different lengths, the extra elements of the
+    /// longer one are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutExt;
+    ///
+    /// let v = &mut [1, 2, 3, 4];
+    /// let mask: Vec<bool> = v.iter().map(|&x| x % 2 == 0).collect();
+    /// for x in v.select_mask_mut(&mask) {
+    ///     *x *= 10;
+    /// }
+    /// assert_eq!(v, &[1, 20, 3, 40]);
+    /// ```
+    fn select_mask_mut<'a, 'b>(
+        &'a mut self,
+        mask: &'b [bool],
+    ) -> SelectMaskMut<'a, 'b, Self::Element> {
+        let this = self.as_mut_slice();
+        SelectMaskMut::new(this, mask)
+    }
+    /// Returns an iterator over mutable references to the elements at the
+    /// indices of the set bits of `mask`, in ascending order.
+    ///
+    /// Returns `None` if the highest set bit is out-of-bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutExt;
+    ///
+    /// let v = &mut [0u8; 64];
+    /// let dirty: u64 = (1 << 63) | (1 << 5) | 1;
+    /// for x in v.get_masked_mut(dirty).unwrap() {
+    ///     *x = 1;
+    /// }
+    /// assert_eq!(v.iter().filter(|&&x| x == 1).count(), 3);
+    /// assert_eq!((v[0], v[5], v[63]), (1, 1, 1));
+    ///
+    /// assert!(v[..63].get_masked_mut(dirty).is_none());
+    /// ```
+    fn get_masked_mut<B: BitMask>(
+        &mut self,
+        mask: B,
+    ) -> Option<MaskedIterMut<'_, Self::Element, B>> {
+        let this = self.as_mut_slice();
+        MaskedIterMut::new(this, mask)
+    }
+    /// Returns mutable references to the first element matching each
+    /// predicate, in a single pass over the slice.
+    ///
+    /// Each element is matched against the predicates in order, and is
+    /// assigned to the first predicate that it matches and that has not
+    /// matched an earlier element. This guarantees that all returned
+    /// references are to distinct elements.
+    ///
+    /// Returns `None` if any predicate does not match any element.
+    ///
+    /// Since all predicates must have the same type, different closures have
+    /// to be coerced to `fn` pointers or `&mut dyn FnMut`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutExt;
+    ///
+    /// let v = &mut [1, 2, 3, 4];
+    /// let predicates: [fn(&i32) -> bool; 2] = [|x| *x > 1, |x| *x % 2 == 0];
+    /// let [big, even] = v.find_many_mut(predicates).unwrap();
+    /// // `2` matches both, but is given to the first predicate.
+    /// assert_eq!((*big, *even), (2, 4));
+    /// core::mem::swap(big, even);
+    /// assert_eq!(v, &[1, 4, 3, 2]);
+    ///
+    /// assert!(v.find_many_mut([|x: &i32| *x > 3; 2]).is_none());
+    /// ```
+    fn find_many_mut<F, const N: usize>(
+        &mut self,
+        mut predicates: [F; N],
+    ) -> Option<[&mut Self::Element; N]>
+    where
+        F: FnMut(&Self::Element) -> bool,
+    {
+        let this = self.as_mut_slice();
+        let mut found: [Option<usize>; N] = [None; N];
+        let mut remaining = N;
+        for (idx, elem) in this.iter().enumerate() {
+            if remaining == 0 {
+                break;
+            }
+            for (predicate, slot) in predicates.iter_mut().zip(&mut found) {
+                if slot.is_none() && predicate(elem) {
+                    *slot = Some(idx);
+                    remaining -= 1;
+                    break;
+                }
+            }
+        }
+        if remaining != 0 {
+            return None;
+        }
+        let indices = found.map(|slot| slot.unwrap_or(0));
+        // SAFETY: Every index was found in bounds, and each element is
+        // assigned to at most one predicate.
+        unsafe {
+            Some(<[Self::Element] as GetManyMutExt>::get_many_unchecked_mut(
+                this, indices,
+            ))
+        }
+    }
+    /// Moves the value at each index to the next index, and the value at the
+    /// last index to the first.
+    ///
+    /// Returns an error if any index is out-of-bounds, or if the same index was
+    /// passed more than once. In that case, `self` is not modified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutExt;
+    ///
+    /// let v = &mut ['a', 'b', 'c', 'd'];
+    /// v.rotate_many([0, 3, 1]).unwrap();
+    /// assert_eq!(v, &['b', 'd', 'c', 'a']);
+    ///
+    /// assert!(v.rotate_many([0, 3, 0]).is_err());
+    /// assert_eq!(v, &['b', 'd', 'c', 'a']);
+    /// ```
+    fn rotate_many<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+    ) -> Result<(), GetManyMutError<N>> {
+        let mut refs = self.get_many_mut(indices)?;
+        if let Some((first, rest)) = refs.split_first_mut() {
+            for other in rest {
+                mem::swap(&mut **first, &mut **other);
+            }
+        }
+        Ok(())
+    }
+    /// Swaps the values of each pair of indices.
+    ///
+    /// All `2 * N` indices are validated once, before any values are swapped.
+    ///
+    /// Returns an error if any index is out-of-bounds, or if the same index
+    /// appears more than once in `pairs`, including twice in the same pair.
+    /// In that case, `self` is not modified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutExt;
+    ///
+    /// let v = &mut [1, 2, 3, 4, 5];
+    /// v.swap_many([(0, 4), (2, 1)]).unwrap();
+    /// assert_eq!(v, &[5, 3, 2, 4, 1]);
+    ///
+    /// assert!(v.swap_many([(0, 4), (4, 1)]).is_err());
+    /// assert!(v.swap_many([(3, 3)]).is_err());
+    /// assert_eq!(v, &[5, 3, 2, 4, 1]);
+    /// ```
+    fn swap_many<const N: usize>(
+        &mut self,
+        pairs: [(usize, usize); N],
+    ) -> Result<(), GetManyMutError<N>> {
+        let this = self.as_mut_slice();
+        if !get_many_check_valid_pairs(&pairs, this.len()) {
+            return Err(GetManyMutError::new());
+        }
+        let ptr: *mut Self::Element = this.as_mut_ptr();
+        // SAFETY: All indices are distinct and in bounds.
+        unsafe {
+            for &(a, b) in &pairs {
+                ptr::swap_nonoverlapping(ptr.add(a), ptr.add(b), 1);
+            }
+        }
+        Ok(())
+    }
+    /// Returns mutable references to many `(row, column)` coordinates at once,
+    /// treating `self` as a row-major grid with rows of length `width`.
+    ///
+    /// Returns an error if any column is not less than `width`, if any
+    /// coordinate is out-of-bounds, or if the same coordinate was passed more
+    /// than once.
+    ///
+    /// For nested arrays, see [`GetManyMut2dExt`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutExt;
+    ///
+    /// let grid = &mut [0; 6];
+    /// let [cell, below] = grid.get_many_mut_2d_flat(3, [(0, 1), (1, 1)]).unwrap();
+    /// *cell = 1;
+    /// *below = 2;
+    /// assert_eq!(grid, &[0, 1, 0, 0, 2, 0]);
+    ///
+    /// assert!(grid.get_many_mut_2d_flat(3, [(0, 3)]).is_err());
+    /// assert!(grid.get_many_mut_2d_flat(3, [(2, 0)]).is_err());
+    /// ```
+    fn get_many_mut_2d_flat<const N: usize>(
+        &mut self,
+        width: usize,
+        coords: [(usize, usize); N],
+    ) -> Result<[&mut Self::Element; N], GetManyMutError<N>> {
+        let mut indices = [0; N];
+        for (idx, &(row, col)) in indices.iter_mut().zip(&coords) {
+            match row
+                .checked_mul(width)
+                .and_then(|start| start.checked_add(col))
+            {
+                Some(flat) if col < width => *idx = flat,
+                _ => return Err(GetManyMutError::new()),
+            }
+        }
+        self.get_many_mut(indices)
+    }
+    /// Returns mutable references to many subslices at once.
+    ///
+    /// Returns an error if any range is out-of-bounds or has its start after
+    /// its end, or if any two ranges overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutExt;
+    ///
+    /// let v = &mut [1, 2, 3, 4, 5];
+    /// let [a, b] = v.get_many_ranges_mut([3..5, 0..2]).unwrap();
+    /// a.swap_with_slice(b);
+    /// assert_eq!(v, &[4, 5, 3, 1, 2]);
+    ///
+    /// assert!(v.get_many_ranges_mut([0..3, 2..4]).is_err());
+    /// assert!(v.get_many_ranges_mut([3..6]).is_err());
+    /// ```
+    fn get_many_ranges_mut<const N: usize>(
+        &mut self,
+        ranges: [Range<usize>; N],
+    ) -> Result<[&mut [Self::Element]; N], GetManyMutError<N>> {
+        let this = self.as_mut_slice();
+        if !get_many_check_valid_ranges(&ranges, this.len()) {
+            return Err(GetManyMutError::new());
+        }
+        let ptr: *mut Self::Element = this.as_mut_ptr();
+        // SAFETY: The ranges are disjoint and in bounds.
+        unsafe {
+            Ok(ranges.map(|range| {
+                slice::from_raw_parts_mut(
+                    ptr.add(range.start),
+                    range.end - range.start,
+                )
+            }))
+        }
+    }
+    /// Returns mutable references to many indices at once, where each index
+    /// may be counted from either end of the slice.
+    ///
+    /// Returns an error if any index is out-of-bounds, or if any two indices
+    /// refer to the same element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::{GetManyMutExt, RelIndex};
+    ///
+    /// let v = &mut [1, 2, 3, 4];
+    /// let [first, last] = v
+    ///     .get_many_rel_mut([RelIndex::FromStart(0), RelIndex::FromEnd(0)])
+    ///     .unwrap();
+    /// core::mem::swap(first, last);
+    /// assert_eq!(v, &[4, 2, 3, 1]);
+    ///
+    /// assert!(v.get_many_rel_mut([RelIndex::FromEnd(4)]).is_err());
+    /// assert!(
+    ///     v.get_many_rel_mut([RelIndex::FromStart(1), RelIndex::FromEnd(2)])
+    ///         .is_err()
+    /// );
+    /// ```
+    fn get_many_rel_mut<const N: usize>(
+        &mut self,
+        indices: [RelIndex; N],
+    ) -> Result<[&mut Self::Element; N], GetManyMutError<N>> {
+        let this = self.as_mut_slice();
+        let len = this.len();
+        let mut resolved = [0; N];
+        for (idx, rel) in resolved.iter_mut().zip(&indices) {
+            *idx = rel.resolve(len).ok_or_else(GetManyMutError::new)?;
+        }
+        <[Self::Element] as GetManyMutExt>::get_many_mut(this, resolved)
+    }
+    /// Returns mutable references to many indices at once, reducing each
+    /// index modulo the length of the slice.
+    ///
+    /// Returns an error if the slice is empty and `N` is not 0, or if any two
+    /// indices refer to the same element after reduction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutExt;
+    ///
+    /// let ring = &mut [1, 2, 3, 4];
+    /// let [a, b] = ring.get_many_mut_wrapping([5, 10]).unwrap();
+    /// core::mem::swap(a, b);
+    /// assert_eq!(ring, &[1, 3, 2, 4]);
+    ///
+    /// assert!(ring.get_many_mut_wrapping([1, 5]).is_err());
+    /// assert!((&mut [] as &mut [i32]).get_many_mut_wrapping([0]).is_err());
+    /// ```
+    fn get_many_mut_wrapping<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+    ) -> Result<[&mut Self::Element; N], GetManyMutError<N>> {
+        let this = self.as_mut_slice();
+        let len = this.len();
+        if len == 0 {
+            return Err(GetManyMutError::new());
+        }
+        <[Self::Element] as GetManyMutExt>::get_many_mut(
+            this,
+            indices.map(|idx| idx % len),
+        )
+    }
+    /// Returns mutable references to two indices at once.
+    ///
+    /// This is equivalent to [`get_many_mut([a,
+    /// b])`](GetManyMutExt::get_many_mut), but returns a tuple.
+    ///
+    /// Returns an error if either index is out-of-bounds, or if `a == b`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutExt;
+    ///
+    /// let v = &mut [1, 2, 3];
+    /// if let Ok((a, b)) = v.get_pair_mut(0, 2) {
+    ///     *a += *b;
+    /// }
+    /// assert_eq!(v, &[4, 2, 3]);
+    ///
+    /// assert!(v.get_pair_mut(1, 1).is_err());
+    /// assert!(v.get_pair_mut(1, 3).is_err());
+    /// ```
+    fn get_pair_mut(
+        &mut self,
+        a: usize,
+        b: usize,
+    ) -> Result<(&mut Self::Element, &mut Self::Element), GetManyMutError<2>>
+    {
+        let this = self.as_mut_slice();
+        let len = this.len();
+        if (a < len) & (b < len) & (a != b) {
+            let ptr: *mut Self::Element = this.as_mut_ptr();
+            // SAFETY: The indices are distinct and in bounds.
+            unsafe { Ok((&mut *ptr.add(a), &mut *ptr.add(b))) }
+        } else {
+            Err(GetManyMutError::new())
+        }
+    }
+    /// Returns mutable references to three indices at once.
+    ///
+    /// This is equivalent to
+    /// [`get_many_mut([a, b, c])`](GetManyMutExt::get_many_mut), but returns a
+    /// tuple.
+    ///
+    /// Returns an error if any index is out-of-bounds, or if any two indices
+    /// are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutExt;
+    ///
+    /// let v = &mut [1, 2, 3];
+    /// if let Ok((a, b, c)) = v.get_triple_mut(0, 1, 2) {
+    ///     *a += *b + *c;
+    /// }
+    /// assert_eq!(v, &[6, 2, 3]);
+    ///
+    /// assert!(v.get_triple_mut(0, 1, 0).is_err());
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn get_triple_mut(
+        &mut self,
+        a: usize,
+        b: usize,
+        c: usize,
+    ) -> Result<
+        (&mut Self::Element, &mut Self::Element, &mut Self::Element),
+        GetManyMutError<3>,
+    > {
+        let this = self.as_mut_slice();
+        let len = this.len();
+        if (a < len) & (b < len) & (c < len) & (a != b) & (a != c) & (b != c) {
+            let ptr: *mut Self::Element = this.as_mut_ptr();
+            // SAFETY: The indices are distinct and in bounds.
+            unsafe {
+                Ok((&mut *ptr.add(a), &mut *ptr.add(b), &mut *ptr.add(c)))
+            }
+        } else {
+            Err(GetManyMutError::new())
+        }
+    }
+    /// Returns mutable references to many indices at once, and an iterator
+    /// over mutable references to all other elements.
+    ///
+    /// Returns an error if any index is out-of-bounds, or if the same index was
+    /// passed more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutExt;
+    ///
+    /// let v = &mut [1, 2, 3, 4, 5];
+    /// let ([pivot], rest) = v.get_many_mut_and_rest([2]).unwrap();
+    /// for x in rest {
+    ///     *x -= *pivot;
+    ///     *pivot += 1;
+    /// }
+    /// assert_eq!(v, &[-2, -2, 7, -1, -1]);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn get_many_mut_and_rest<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+    ) -> Result<
+        ([&mut Self::Element; N], RestMut<'_, Self::Element, N>),
+        GetManyMutError<N>,
+    > {
+        let this = self.as_mut_slice();
+        let len = this.len();
+        if !get_many_check_valid(&indices, len) {
+            return Err(GetManyMutError::new());
+        }
+        let ptr = NonNull::from(this).cast::<Self::Element>();
+        // SAFETY: `indices` are distinct and in bounds, and `RestMut` only
+        // accesses the other elements.
+        unsafe {
+            let selected =
+                <[Self::Element] as GetManyMutExt>::get_many_unchecked_mut(
+                    slice::from_raw_parts_mut(ptr.as_ptr(), len),
+                    indices,
+                );
+            Ok((selected, RestMut::new(ptr, len, indices)))
+        }
+    }
+    /// Returns mutable references to many indices at once, checking that they
+    /// are in bounds but not that they are distinct.
+    ///
+    /// This is useful when the indices are known to be distinct, for example
+    /// because they come from a set.
+    ///
+    /// For a safe alternative see [`get_many_mut`].
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with overlapping indices is *[undefined behavior]*
+    /// even if the resulting references are not used.
+    ///
+    /// This is checked in some builds, see [checked
+    /// preconditions](crate#checked-preconditions).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutExt;
+    ///
+    /// let x = &mut [1, 2, 4];
+    ///
+    /// unsafe {
+    ///     let [a, b] = x.get_many_unchecked_disjoint_mut([0, 2]).unwrap();
+    ///     *a *= 10;
+    ///     *b *= 100;
+    ///     assert!(x.get_many_unchecked_disjoint_mut([0, 3]).is_err());
+    /// }
+    /// assert_eq!(x, &[10, 2, 400]);
+    /// ```
+    ///
+    /// [`get_many_mut`]: GetManyMutExt::get_many_mut
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    unsafe fn get_many_unchecked_disjoint_mut<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+    ) -> Result<[&mut Self::Element; N], GetManyMutError<N>> {
+        let this = self.as_mut_slice();
+        assert_unsafe_precondition!(
+            get_many_check_disjoint(&indices),
+            "get_many_unchecked_disjoint_mut requires distinct indices",
+        );
+        if get_many_check_in_bounds(&indices, this.len()) {
+            // SAFETY: The caller guarantees that `indices` are distinct.
+            unsafe {
+                Ok(<[Self::Element] as GetManyMutExt>::get_many_unchecked_mut(
+                    this, indices,
+                ))
+            }
+        } else {
+            Err(GetManyMutError::new())
+        }
+    }
+    /// Returns mutable references to many indices at once, checking that they
+    /// are distinct but not that they are in bounds.
+    ///
+    /// For a safe alternative see [`get_many_mut`].
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with out-of-bounds indices is
+    /// *[undefined behavior]* even if the resulting references are not
+    /// used.
+    ///
+    /// This is checked in some builds, see [checked
+    /// preconditions](crate#checked-preconditions).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutExt;
+    ///
+    /// let x = &mut [1, 2, 4];
+    ///
+    /// unsafe {
+    ///     let [a, b] = x.get_many_unchecked_inbounds_mut([0, 2]).unwrap();
+    ///     *a *= 10;
+    ///     *b *= 100;
+    ///     assert!(x.get_many_unchecked_inbounds_mut([0, 0]).is_err());
+    /// }
+    /// assert_eq!(x, &[10, 2, 400]);
+    /// ```
+    ///
+    /// [`get_many_mut`]: GetManyMutExt::get_many_mut
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    unsafe fn get_many_unchecked_inbounds_mut<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+    ) -> Result<[&mut Self::Element; N], GetManyMutError<N>> {
+        let this = self.as_mut_slice();
+        assert_unsafe_precondition!(
+            get_many_check_in_bounds(&indices, this.len()),
+            "get_many_unchecked_inbounds_mut requires in-bounds indices",
+        );
+        if get_many_check_disjoint(&indices) {
+            // SAFETY: The caller guarantees that `indices` are in bounds.
+            unsafe {
+                Ok(<[Self::Element] as GetManyMutExt>::get_many_unchecked_mut(
+                    this, indices,
+                ))
+            }
+        } else {
+            Err(GetManyMutError::new())
+        }
+    }
+    /// Calls `f` with mutable references to many indices at once, and returns
+    /// its result.
+    ///
+    /// This is equivalent to
+    /// [`get_many_mut(indices).map(f)`](GetManyMutExt::get_many_mut), but the
+    /// references cannot outlive the call to `f`.
+    ///
+    /// Returns an error without calling `f` if any index is out-of-bounds, or
+    /// if the same index was passed more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutExt;
+    ///
+    /// let v = &mut [1, 2, 3, 4];
+    /// let sums: Vec<i32> = [[0, 1], [2, 3]]
+    ///     .iter()
+    ///     .filter_map(|&indices| {
+    ///         v.with_many_mut(indices, |[a, b]| {
+    ///             *a += *b;
+    ///             *a
+    ///         })
+    ///         .ok()
+    ///     })
+    ///     .collect();
+    /// assert_eq!(sums, [3, 7]);
+    /// assert_eq!(v, &[3, 2, 7, 4]);
+    ///
+    /// assert!(v.with_many_mut([1, 1], |_| ()).is_err());
+    /// ```
+    fn with_many_mut<R, F, const N: usize>(
+        &mut self,
+        indices: [usize; N],
+        f: F,
+    ) -> Result<R, GetManyMutError<N>>
+    where
+        F: FnOnce([&mut Self::Element; N]) -> R,
+    {
+        self.get_many_mut(indices).map(f)
+    }
+    /// Returns shared references to many indices at once.
+    ///
+    /// This is the shared counterpart of [`get_many_mut`], for generic code
+    /// that needs both. Since shared references may alias, the same index may
+    /// be passed more than once.
+    ///
+    /// Returns an error if any index is out-of-bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutExt;
+    ///
+    /// let v = &mut [1, 2, 3];
+    /// let [a, b, c] = v.get_many_ref([2, 0, 2]).unwrap();
+    /// assert_eq!((*a, *b, *c), (3, 1, 3));
+    ///
+    /// assert!(v.get_many_ref([0, 3]).is_err());
+    /// ```
+    ///
+    /// [`get_many_mut`]: GetManyMutExt::get_many_mut
+    fn get_many_ref<const N: usize>(
+        &self,
+        indices: [usize; N],
+    ) -> Result<[&Self::Element; N], GetManyMutError<N>> {
+        let this = self.as_slice();
+        if get_many_check_in_bounds(&indices, this.len()) {
+            unsafe {
+                Ok(<[Self::Element] as GetManyMutExt>::get_many_unchecked_ref(
+                    this, indices,
+                ))
+            }
+        } else {
+            Err(GetManyMutError::new())
+        }
+    }
+    /// Returns shared references to many indices at once, without doing any
+    /// checks.
+    ///
+    /// For a safe alternative see [`get_many_ref`].
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with out-of-bounds indices is
+    /// *[undefined behavior]* even if the resulting references are not used.
+    ///
+    /// This is checked in some builds, see [checked
+    /// preconditions](crate#checked-preconditions).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutExt;
+    ///
+    /// let v = &[1, 2, 3];
+    /// let [a, b] = unsafe { v.get_many_unchecked_ref([1, 1]) };
+    /// assert_eq!(*a + *b, 4);
+    /// ```
+    ///
+    /// [`get_many_ref`]: GetManyMutExt::get_many_ref
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    unsafe fn get_many_unchecked_ref<const N: usize>(
+        &self,
+        indices: [usize; N],
+    ) -> [&Self::Element; N] {
+        let this = self.as_slice();
+        assert_unsafe_precondition!(
+            get_many_check_in_bounds(&indices, this.len()),
+            "get_many_unchecked_ref requires in-bounds indices",
+        );
+        // NB: Unlike in `get_many_unchecked_mut`, the references may alias, so
+        // there's no need to go through a raw pointer.
+        indices.map(|idx| {
+            // SAFETY: We expect `indices` to contain values that are in bounds
+            // of `self`.
+            unsafe { this.get_unchecked(idx) }
+        })
+    }
+    /// Returns iterators over many columns of a row-major matrix at once.
+    ///
+    /// `self` is treated as rows of `width` elements each, and each iterator
+    /// yields mutable references to the elements of one column, from the
+    /// first row to the last. A trailing partial row is ignored, like with
+    /// [`chunks_exact_mut`](slice::chunks_exact_mut).
+    ///
+    /// Returns an error if any column is not less than `width`, or if the same
+    /// column was passed more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutExt;
+    ///
+    /// let matrix = &mut [1, 2, 3, 4, 5, 6];
+    /// let [first, last] = matrix.get_columns_mut(3, [0, 2]).unwrap();
+    /// for (a, b) in first.zip(last) {
+    ///     core::mem::swap(a, b);
+    /// }
+    /// assert_eq!(matrix, &[3, 2, 1, 6, 5, 4]);
+    ///
+    /// assert!(matrix.get_columns_mut(3, [1, 1]).is_err());
+    /// assert!(matrix.get_columns_mut(3, [3]).is_err());
+    /// ```
+    fn get_columns_mut<const N: usize>(
+        &mut self,
+        width: usize,
+        cols: [usize; N],
+    ) -> Result<[StridedMut<'_, Self::Element>; N], GetManyMutError<N>> {
+        let this = self.as_mut_slice();
+        if get_many_check_valid(&cols, width) {
+            let rows = this.len() / width.max(1);
+            let ptr = NonNull::from(this).cast::<Self::Element>();
+            // SAFETY: The columns are distinct and less than `width`, so every
+            // element of each iterator is in bounds, and only in that iterator.
+            // If there are no rows, the start of a column may be out of
+            // bounds, but then it is never used.
+            Ok(cols.map(|col| unsafe {
+                let start = if rows == 0 {
+                    ptr
+                } else {
+                    NonNull::new_unchecked(ptr.as_ptr().add(col))
+                };
+                StridedMut::new(start, width, rows)
+            }))
+        } else {
+            Err(GetManyMutError::new())
+        }
+    }
+    /// Returns mutable references to the elements of many groups of indices
+    /// at once.
+    ///
+    /// Returns an error if any index is out-of-bounds, or if the same index
+    /// appears more than once, in the same group or in different groups.
+    ///
+    /// This does not run anything in parallel itself. Each group of
+    /// references can be sent to a different thread by the caller (e.g. with
+    /// `rayon`'s `into_par_iter`) for scatter-style parallel updates.
+    ///
+    /// This sorts a copy of all indices, doing `O(M log M)` operations for `M`
+    /// indices in total.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutExt;
+    ///
+    /// let v = &mut [0; 6];
+    /// let groups =
+    ///     v.get_disjoint_groups_mut(&[vec![0, 5], vec![2], vec![]]).unwrap();
+    /// for (i, group) in groups.into_iter().enumerate() {
+    ///     for elem in group {
+    ///         *elem = i + 1;
+    ///     }
+    /// }
+    /// assert_eq!(v, &[1, 0, 2, 0, 0, 1]);
+    ///
+    /// assert!(v.get_disjoint_groups_mut(&[vec![0, 1], vec![1]]).is_err());
+    /// assert!(v.get_disjoint_groups_mut(&[vec![6]]).is_err());
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn get_disjoint_groups_mut<G: AsRef<[usize]>>(
+        &mut self,
+        groups: &[G],
+    ) -> Result<Vec<Vec<&mut Self::Element>>, GetManyMutErrorDyn> {
+        let this = self.as_mut_slice();
+        let mut sorted: Vec<usize> =
+            groups.iter().flat_map(|group| group.as_ref()).copied().collect();
+        sorted.sort_unstable();
+        let mut valid = sorted.last().map_or(true, |&last| last < this.len());
+        for pair in sorted.windows(2) {
+            valid &= pair[0] != pair[1];
+        }
+        if !valid {
+            return Err(GetManyMutErrorDyn);
+        }
+
+        let ptr: *mut Self::Element = this.as_mut_ptr();
+        // SAFETY: The indices are distinct and in bounds of `self`.
+        Ok(groups
+            .iter()
+            .map(|group| {
+                group
+                    .as_ref()
+                    .iter()
+                    .map(|&idx| unsafe { &mut *ptr.add(idx) })
+                    .collect()
+            })
+            .collect())
+    }
+    /// Returns mutable references to many groups of indices at once, keeping
+    /// the groups separate.
+    ///
+    /// This is like [`get_many_mut`] on all `K * G` indices, but the
+    /// references are returned in the same shape as `groups`.
+    ///
+    /// Returns an error if any index is out-of-bounds, or if the same index
+    /// appears more than once, in the same group or in different groups.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutExt;
+    ///
+    /// let vertices = &mut [0; 5];
+    /// let triangles = [[0, 1, 2], [3, 4, 1]];
+    /// assert!(vertices.get_many_groups_mut(triangles).is_err());
+    ///
+    /// let [[a, b], [c, d]] =
+    ///     vertices.get_many_groups_mut([[0, 1], [3, 4]]).unwrap();
+    /// *a = 1;
+    /// *b = 2;
+    /// *c = 3;
+    /// *d = 4;
+    /// assert_eq!(vertices, &[1, 2, 0, 3, 4]);
+    /// ```
+    ///
+    /// [`get_many_mut`]: GetManyMutExt::get_many_mut
+    fn get_many_groups_mut<const K: usize, const G: usize>(
+        &mut self,
+        groups: [[usize; K]; G],
+    ) -> Result<[[&mut Self::Element; K]; G], GetManyMutError<G>> {
+        let this = self.as_mut_slice();
+        // SAFETY: `[[usize; K]; G]` has the same layout as `[usize; K * G]`.
+        let indices: &[usize] =
+            unsafe { slice::from_raw_parts(groups.as_ptr().cast(), K * G) };
+        if !indices_are_disjoint(indices, this.len()) {
+            return Err(GetManyMutError::new());
+        }
+
+        let ptr: *mut Self::Element = this.as_mut_ptr();
+        let mut arr: mem::MaybeUninit<[[&mut Self::Element; K]; G]> =
+            mem::MaybeUninit::uninit();
+        let arr_ptr: *mut *mut Self::Element = arr.as_mut_ptr().cast();
+
+        // SAFETY: The indices are disjoint and in bounds, and `arr` has the
+        // same layout as `indices`.
+        unsafe {
+            for (i, &idx) in indices.iter().enumerate() {
+                *arr_ptr.add(i) = ptr.add(idx);
+            }
+            Ok(arr.assume_init())
+        }
+    }
+    /// Returns mutable references to a part of the elements at many indices
+    /// at once.
+    ///
+    /// `f` is called on each element, and should return a reference to e.g.
+    /// one of its fields. Only the projected references are returned, so the
+    /// rest of each element can no longer be reached through them.
+    ///
+    /// Returns an error without calling `f` if any index is out-of-bounds, or
+    /// if the same index was passed more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutExt;
+    ///
+    /// struct Entity {
+    ///     name: &'static str,
+    ///     health: u32,
+    /// }
+    ///
+    /// let entities = &mut [
+    ///     Entity { name: "a", health: 10 },
+    ///     Entity { name: "b", health: 20 },
+    /// ];
+    /// let [a, b] =
+    ///     entities.project_many_mut([0, 1], |entity| &mut entity.health).unwrap();
+    /// core::mem::swap(a, b);
+    /// assert_eq!((entities[0].name, entities[0].health), ("a", 20));
+    /// ```
+    fn project_many_mut<U, F, const N: usize>(
+        &mut self,
+        indices: [usize; N],
+        f: F,
+    ) -> Result<[&mut U; N], GetManyMutError<N>>
+    where
+        U: ?Sized,
+        F: FnMut(&mut Self::Element) -> &mut U,
+    {
+        Ok(self.get_many_mut(indices)?.map(f))
+    }
+    /// Returns mutable references to a runtime-sized number of indices at
+    /// once, without allocating.
+    ///
+    /// The references are written to the start of `out`, and the initialized
+    /// part of `out` is returned.
+    ///
+    /// Returns an error if any index is out-of-bounds, or if the same index was
+    /// passed more than once. This does every pairwise comparison, so it is
+    /// `O(M²)` for `M` indices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `indices`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::mem::MaybeUninit;
+    /// use get_many_mut::GetManyMutExt;
+    ///
+    /// let v = &mut [1, 2, 3, 4];
+    /// let indices: &[usize] = &[3, 0, 2];
+    /// {
+    ///     let mut buf = [(); 4].map(|()| MaybeUninit::uninit());
+    ///     let refs = v.get_many_mut_into(indices, &mut buf).unwrap();
+    ///     for elem in refs.iter_mut() {
+    ///         **elem *= 10;
+    ///     }
+    /// }
+    /// assert_eq!(v, &[10, 2, 30, 40]);
+    ///
+    /// let mut buf = [(); 2].map(|()| MaybeUninit::uninit());
+    /// assert!(v.get_many_mut_into(&[1, 1], &mut buf).is_err());
+    /// ```
+    fn get_many_mut_into<'a, 'b>(
+        &'a mut self,
+        indices: &[usize],
+        out: &'b mut [mem::MaybeUninit<&'a mut Self::Element>],
+    ) -> Result<&'b mut [&'a mut Self::Element], GetManyMutErrorDyn> {
+        let this = self.as_mut_slice();
+        let out = &mut out[..indices.len()];
+        if !indices_are_disjoint(indices, this.len()) {
+            return Err(GetManyMutErrorDyn);
+        }
+
+        let ptr: *mut Self::Element = this.as_mut_ptr();
+        for (elem, &idx) in out.iter_mut().zip(indices) {
+            // SAFETY: The indices are distinct and in bounds of `self`.
+            *elem = mem::MaybeUninit::new(unsafe { &mut *ptr.add(idx) });
+        }
+        // SAFETY: Every element of `out` was initialized above, and
+        // `MaybeUninit<X>` has the same layout as `X`.
+        Ok(unsafe {
+            &mut *(out as *mut [mem::MaybeUninit<&'a mut Self::Element>]
+                as *mut [&'a mut Self::Element])
+        })
+    }
+    /// Returns mutable references to many coordinates of a `D`-dimensional
+    /// array at once, treating `self` as a row-major array of the given
+    /// `shape`.
+    ///
+    /// This generalizes [`get_many_mut_2d_flat`] to any number of axes. It can
+    /// be used with e.g. `ndarray`'s arrays in standard layout, through their
+    /// `as_slice_mut` method. For other layouts, see
+    /// [`get_many_mut_nd_strided`].
+    ///
+    /// Returns an error if any coordinate is not less than the length of its
+    /// axis, if any coordinate is out-of-bounds of `self`, or if the same
+    /// coordinate was passed more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutExt;
+    ///
+    /// let volume = &mut [0; 2 * 3 * 4];
+    /// let [cell, right, below] = volume
+    ///     .get_many_mut_nd_flat([2, 3, 4], [[1, 1, 1], [1, 1, 2], [1, 2, 1]])
+    ///     .unwrap();
+    /// *cell = 1;
+    /// *right = 2;
+    /// *below = 3;
+    /// assert_eq!(volume[12 + 4 + 1..][..6], [1, 2, 0, 0, 3, 0]);
+    ///
+    /// assert!(volume.get_many_mut_nd_flat([2, 3, 4], [[0, 3, 0]]).is_err());
+    /// assert!(volume.get_many_mut_nd_flat([3, 3, 4], [[2, 0, 0]]).is_err());
+    /// ```
+    ///
+    /// [`get_many_mut_2d_flat`]: GetManyMutExt::get_many_mut_2d_flat
+    /// [`get_many_mut_nd_strided`]: GetManyMutExt::get_many_mut_nd_strided
+    fn get_many_mut_nd_flat<const D: usize, const N: usize>(
+        &mut self,
+        shape: [usize; D],
+        coords: [[usize; D]; N],
+    ) -> Result<[&mut Self::Element; N], GetManyMutError<N>> {
+        let mut indices = [0; N];
+        for (idx, coord) in indices.iter_mut().zip(&coords) {
+            let mut flat: usize = 0;
+            for (&pos, &axis_len) in coord.iter().zip(&shape) {
+                match flat
+                    .checked_mul(axis_len)
+                    .and_then(|start| start.checked_add(pos))
+                {
+                    Some(next) if pos < axis_len => flat = next,
+                    _ => return Err(GetManyMutError::new()),
+                }
+            }
+            *idx = flat;
+        }
+        self.get_many_mut(indices)
+    }
+    /// Returns mutable references to many coordinates of a `D`-dimensional
+    /// array at once, treating `self` as the memory of an array with the
+    /// given `shape` and `strides`.
+    ///
+    /// The element at `coord` is `self[offset + sum(coord[i] * strides[i])]`.
+    /// Strides are counted in elements, and may be negative or zero. For
+    /// e.g. an `ndarray` array whose elements are contiguous in memory (see
+    /// its `as_slice_memory_order_mut` method), these are its `shape()` and
+    /// `strides()`, and `offset` is the position of the element at all-zero
+    /// coordinates in that slice. With negative strides, that element is not
+    /// at the start of the slice: the offset is the sum of
+    /// `(shape[i] - 1) * -strides[i]` over the axes with negative strides.
+    /// There is no direct `ndarray` integration.
+    ///
+    /// Returns an error if any coordinate is not less than the length of its
+    /// axis, if any coordinate is out-of-bounds of `self`, or if two
+    /// coordinates refer to the same element, which is possible with strides
+    /// that overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutExt;
+    ///
+    /// // A 2x3 matrix in column-major order.
+    /// let matrix = &mut [0, 1, 2, 3, 4, 5];
+    /// let [a, b] = matrix
+    ///     .get_many_mut_nd_strided([2, 3], [1, 2], 0, [[0, 1], [1, 2]])
+    ///     .unwrap();
+    /// core::mem::swap(a, b);
+    /// assert_eq!(matrix, &[0, 1, 5, 3, 4, 2]);
+    ///
+    /// // The same matrix, with its rows in reverse order.
+    /// let [a, b] = matrix
+    ///     .get_many_mut_nd_strided([2, 3], [-1, 2], 1, [[0, 1], [1, 2]])
+    ///     .unwrap();
+    /// core::mem::swap(a, b);
+    /// assert_eq!(matrix, &[0, 1, 5, 4, 3, 2]);
+    ///
+    /// // A row broadcast to two rows, so both rows share their elements.
+    /// assert!(
+    ///     matrix
+    ///         .get_many_mut_nd_strided([2, 3], [0, 1], 0, [[0, 1], [1, 1]])
+    ///         .is_err()
+    /// );
+    /// assert!(
+    ///     matrix.get_many_mut_nd_strided([2, 3], [-1, 2], 0, [[1, 0]]).is_err()
+    /// );
+    /// ```
+    fn get_many_mut_nd_strided<const D: usize, const N: usize>(
+        &mut self,
+        shape: [usize; D],
+        strides: [isize; D],
+        offset: usize,
+        coords: [[usize; D]; N],
+    ) -> Result<[&mut Self::Element; N], GetManyMutError<N>> {
+        let start = isize::try_from(offset).ok();
+        let mut indices = [0; N];
+        for (idx, coord) in indices.iter_mut().zip(&coords) {
+            let mut flat = start;
+            for ((&pos, &axis_len), &stride) in
+                coord.iter().zip(&shape).zip(&strides)
+            {
+                if pos >= axis_len {
+                    return Err(GetManyMutError::new());
+                }
+                flat = flat.and_then(|flat| {
+                    let step =
+                        isize::try_from(pos).ok()?.checked_mul(stride)?;
+                    flat.checked_add(step)
+                });
+            }
+            *idx = match flat.and_then(|flat| usize::try_from(flat).ok()) {
+                Some(flat) => flat,
+                None => return Err(GetManyMutError::new()),
+            };
+        }
+        self.get_many_mut(indices)
+    }
+    /// Returns mutable references to many indices at once, allowing repeated
+    /// indices.
+    ///
+    /// The first occurrence of each index is `Some`, and every later
+    /// occurrence of the same index is `None`.
+    ///
+    /// Returns an error if any index is out-of-bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutExt;
+    ///
+    /// let v = &mut [1, 2, 3];
+    /// let [a, b, c] = v.get_many_mut_dedup([2, 0, 2]).unwrap();
+    /// assert!(c.is_none());
+    /// core::mem::swap(a.unwrap(), b.unwrap());
+    /// assert_eq!(v, &[3, 2, 1]);
+    ///
+    /// assert!(v.get_many_mut_dedup([0, 3]).is_err());
+    /// ```
+    fn get_many_mut_dedup<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+    ) -> Result<[Option<&mut Self::Element>; N], GetManyMutError<N>> {
+        let this = self.as_mut_slice();
+        if !get_many_check_in_bounds(&indices, this.len()) {
+            return Err(GetManyMutError::new());
+        }
+
+        let ptr: *mut Self::Element = this.as_mut_ptr();
+        let mut i = 0;
+        Ok(indices.map(|idx| {
+            let first = !indices[..i].contains(&idx);
+            i += 1;
+            // SAFETY: `idx` is in bounds, and only the first occurrence of
+            // each index gets a reference.
+            first.then(|| unsafe { &mut *ptr.add(idx) })
+        }))
+    }
+    /// Returns an iterator over mutable references to every element except
+    /// those at `excluded`, in ascending order.
+    ///
+    /// This is [`get_many_mut_and_rest`] without the references to the
+    /// excluded elements, which stay untouched.
+    ///
+    /// Returns an error if any excluded index is out-of-bounds, or if the same
+    /// index was passed more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutExt;
+    ///
+    /// let v = &mut [1, 2, 3, 4, 5];
+    /// for elem in v.get_all_except_mut([1, 3]).unwrap() {
+    ///     *elem = 0;
+    /// }
+    /// assert_eq!(v, &[0, 2, 0, 4, 0]);
+    ///
+    /// assert!(v.get_all_except_mut([5]).is_err());
+    /// ```
+    ///
+    /// [`get_many_mut_and_rest`]: GetManyMutExt::get_many_mut_and_rest
+    fn get_all_except_mut<const N: usize>(
+        &mut self,
+        excluded: [usize; N],
+    ) -> Result<RestMut<'_, Self::Element, N>, GetManyMutError<N>> {
+        let (_, rest) = self.get_many_mut_and_rest(excluded)?;
+        Ok(rest)
+    }
+    /// Replaces the elements at many indices at once, returning the previous
+    /// elements.
+    ///
+    /// `values[i]` is written to `indices[i]`, and the old element there is
+    /// returned at the same position.
+    ///
+    /// Returns an error if any index is out-of-bounds, or if the same index was
+    /// passed more than once. In that case, `self` is not modified, and
+    /// `values` are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutExt;
+    ///
+    /// let v = &mut [1, 2, 3, 4];
+    /// assert_eq!(v.set_many([3, 0], [40, 10]).unwrap(), [4, 1]);
+    /// assert_eq!(v, &[10, 2, 3, 40]);
+    ///
+    /// assert!(v.set_many([1, 1], [0, 0]).is_err());
+    /// assert_eq!(v, &[10, 2, 3, 40]);
+    /// ```
+    fn set_many<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+        mut values: [Self::Element; N],
+    ) -> Result<[Self::Element; N], GetManyMutError<N>> {
+        let refs = self.get_many_mut(indices)?;
+        for (elem, value) in IntoIterator::into_iter(refs).zip(&mut values) {
+            mem::swap(elem, value);
+        }
+        Ok(values)
+    }
+    /// Sets the elements at many indices at once to clones of `value`.
+    ///
+    /// Like [`slice::fill`], `value` itself is moved into the last index.
+    ///
+    /// Returns an error if any index is out-of-bounds, or if the same index was
+    /// passed more than once. In that case, `self` is not modified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutExt;
+    ///
+    /// let v = &mut [String::new(), String::new(), String::new()];
+    /// v.fill_many([0, 2], String::from("x")).unwrap();
+    /// assert_eq!(v, &["x", "", "x"]);
+    ///
+    /// assert!(v.fill_many([3], String::new()).is_err());
+    /// ```
+    fn fill_many<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+        value: Self::Element,
+    ) -> Result<(), GetManyMutError<N>>
+    where
+        Self::Element: Clone,
+    {
+        let mut refs = self.get_many_mut(indices)?;
+        if let Some((last, rest)) = refs.split_last_mut() {
+            for elem in rest {
+                **elem = value.clone();
+            }
+            **last = value;
+        }
+        Ok(())
+    }
+}
+
+unsafe impl<T> GetManyMutExt for [T] {
+    type Element = T;
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+// SAFETY: `RawSliceAccess` guarantees that the slice described by
+// `as_raw_parts(_mut)` is valid.
+unsafe impl<T, C> GetManyMutExt for C
+where
+    C: RawSliceAccess<Element = T> + ?Sized,
+{
+    type Element = T;
+    fn as_slice(&self) -> &[T] {
+        raw_slice(self)
+    }
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        raw_slice_mut(self)
+    }
+}
+
+fn raw_slice<C: RawSliceAccess + ?Sized>(container: &C) -> &[C::Element] {
+    let (ptr, len) = container.as_raw_parts();
+    // SAFETY: `RawSliceAccess` guarantees that this is a valid slice for as
+    // long as `container` is borrowed.
+    unsafe { slice::from_raw_parts(ptr, len) }
+}
+
+fn raw_slice_mut<C: RawSliceAccess + ?Sized>(
+    container: &mut C,
+) -> &mut [C::Element] {
+    let (ptr, len) = container.as_raw_parts_mut();
+    // SAFETY: `RawSliceAccess` guarantees that this is a valid slice, and not
+    // accessed otherwise, for as long as `container` is borrowed.
+    unsafe { slice::from_raw_parts_mut(ptr, len) }
+}
+
+/// The error type returned by
+/// [`get_many_mut<N>`][`GetManyMutExt::get_many_mut`].
+///
+/// It indicates one of two possible errors:
+/// - An index is out-of-bounds.
+/// - The same index appeared multiple times in the array.
+///
+/// # Examples
+///
+/// ```
+/// use get_many_mut::GetManyMutExt;
+///
+/// let v = &mut [1, 2, 3];
+/// assert!(v.get_many_mut([0, 999]).is_err());
+/// assert!(v.get_many_mut([1, 1]).is_err());
+/// ```
+// NB: The N here is there to be forward-compatible with adding more details
+// to the error type at a later point
+#[non_exhaustive]
+pub struct GetManyMutError<const N: usize>;
+
+impl<const N: usize> GetManyMutError<N> {
+    /// Constructs the error out of line, so that the error paths are kept
+    /// away from the happy path.
+    #[cold]
+    #[inline(never)]
+    fn new() -> Self {
+        GetManyMutError
+    }
+}
+
 impl<const N: usize> fmt::Debug for GetManyMutError<N> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("GetManyMutError").finish_non_exhaustive()
@@ -185,5 +2201,70 @@ impl<const N: usize> fmt::Display for GetManyMutError<N> {
     }
 }
 
-#[cfg(feature = "std")]
+#[cfg(all(feature = "std", not(feature = "core-error")))]
 impl<const N: usize> std::error::Error for GetManyMutError<N> {}
+
+// NB: `std::error::Error` is a re-export of `core::error::Error` on Rust
+// versions that have the latter, so this impl also covers `std` users.
+#[cfg(feature = "core-error")]
+impl<const N: usize> core::error::Error for GetManyMutError<N> {}
+
+/// A variant of [`GetManyMutError`] that does not depend on the number of
+/// indices.
+///
+/// This is useful for functions that call [`get_many_mut`] with several
+/// different `N`s, but want to return a single error type. Any
+/// [`GetManyMutError<N>`] converts into it, so it works with `?`.
+///
+/// # Examples
+///
+/// ```
+/// use get_many_mut::{GetManyMutErrorDyn, GetManyMutExt};
+///
+/// fn bump(v: &mut [i32]) -> Result<(), GetManyMutErrorDyn> {
+///     let [a, b] = v.get_many_mut([0, 1])?;
+///     *a += 1;
+///     *b += 1;
+///     let [c, d, e] = v.get_many_mut([2, 3, 4])?;
+///     *c += 1;
+///     *d += 1;
+///     *e += 1;
+///     Ok(())
+/// }
+///
+/// let mut v = [0; 5];
+/// bump(&mut v).unwrap();
+/// assert_eq!(v, [1; 5]);
+/// assert!(bump(&mut v[..4]).is_err());
+/// ```
+///
+/// [`get_many_mut`]: GetManyMutExt::get_many_mut
+#[non_exhaustive]
+pub struct GetManyMutErrorDyn;
+
+impl<const N: usize> From<GetManyMutError<N>> for GetManyMutErrorDyn {
+    fn from(_: GetManyMutError<N>) -> Self {
+        GetManyMutErrorDyn
+    }
+}
+
+impl fmt::Debug for GetManyMutErrorDyn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GetManyMutErrorDyn").finish_non_exhaustive()
+    }
+}
+
+impl fmt::Display for GetManyMutErrorDyn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(
+            "an index is out of bounds or appeared multiple times in the array",
+            f,
+        )
+    }
+}
+
+#[cfg(all(feature = "std", not(feature = "core-error")))]
+impl std::error::Error for GetManyMutErrorDyn {}
+
+#[cfg(feature = "core-error")]
+impl core::error::Error for GetManyMutErrorDyn {}
@@ -0,0 +1,88 @@
+/// Binds mutable references to many elements of a slice at once.
+///
+/// `many_mut!(v; a = 0, b = 2)` is like
+/// `let [a, b] = v.get_many_mut([0, 2]).unwrap();`, and works for anything
+/// that can be indexed with `[..]` to get a mutable slice.
+///
+/// If every index is an integer literal, duplicate indices are rejected at
+/// compile time, and only the bounds are checked at runtime. Otherwise this
+/// falls back to [`get_many_mut`].
+///
+/// # Panics
+///
+/// Panics if any index is out of bounds, or if non-literal indices overlap.
+///
+/// # Examples
+///
+/// ```
+/// use get_many_mut::many_mut;
+///
+/// let mut v = vec![1, 2, 3, 4, 5, 6];
+/// many_mut!(v; a = 0, b = 2, c = 5);
+/// *a += 10;
+/// *b += 20;
+/// *c += 30;
+/// assert_eq!(v, [11, 2, 23, 4, 5, 36]);
+///
+/// let i = 3;
+/// many_mut!(v; d = i, e = i + 1);
+/// core::mem::swap(d, e);
+/// assert_eq!(v, [11, 2, 23, 5, 4, 36]);
+/// ```
+///
+/// Duplicate literal indices do not compile:
+///
+/// ```compile_fail
+/// use get_many_mut::many_mut;
+///
+/// let mut v = [1, 2, 3];
+/// many_mut!(v; a = 1, b = 1);
+/// ```
+///
+/// The slice expression is not evaluated in an `unsafe` context:
+///
+/// ```compile_fail,E0133
+/// use get_many_mut::many_mut;
+///
+/// let mut v = [1, 2, 3];
+/// let p: *mut [i32; 3] = &mut v;
+/// many_mut!(*p; a = 0, b = 2);
+/// ```
+///
+/// [`get_many_mut`]: crate::GetManyMutExt::get_many_mut
+#[macro_export]
+macro_rules! many_mut {
+    ($slice:expr; $($name:ident = $index:literal),+ $(,)?) => {
+        // NB: This fails to compile (by underflowing an array length) if the
+        // literal indices are not disjoint. `usize::MAX` is never in bounds,
+        // so the bounds are left to the runtime check.
+        const _: () = {
+            let _ = [(); 0 - !$crate::indices_are_disjoint(
+                &[$($index),+],
+                usize::MAX,
+            ) as usize];
+        };
+        // NB: `$slice` is evaluated outside of the `unsafe` block, so that it
+        // can't do unsafe operations itself.
+        let slice: &mut [_] = &mut $slice[..];
+        let [$($name),+] = match unsafe {
+            // SAFETY: The indices were checked to be disjoint above.
+            <[_] as $crate::GetManyMutExt>::get_many_unchecked_disjoint_mut(
+                slice,
+                [$($index),+],
+            )
+        } {
+            Ok(refs) => refs,
+            Err(_) => panic!("many_mut!: index out of bounds"),
+        };
+    };
+    ($slice:expr; $($name:ident = $index:expr),+ $(,)?) => {
+        let [$($name),+] = match <[_] as $crate::GetManyMutExt>::get_many_mut(
+            &mut $slice[..],
+            [$($index),+],
+        ) {
+            Ok(refs) => refs,
+            Err(_) => panic!("many_mut!: index out of bounds or overlapping"),
+        };
+    };
+}
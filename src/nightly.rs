@@ -0,0 +1,138 @@
+use crate::{GetManyMutError, GetManyMutExt, indices_are_disjoint};
+
+/// Extension trait for
+/// [`get_many_const_mut`](GetManyConstMutExt::get_many_const_mut).
+///
+/// This requires the `nightly` feature, since it uses slice-typed const
+/// generic parameters.
+// NB: The indices are a `&'static [usize]` rather than a `[usize; N]`, since
+// the type of a const parameter cannot yet depend on another generic
+// parameter in a trait method.
+pub trait GetManyConstMutExt {
+    type Element;
+    /// The type returned by
+    /// [`get_many_const_mut`](GetManyConstMutExt::get_many_const_mut).
+    ///
+    /// For slices this is a `Result`, since the length is only known at
+    /// runtime. For arrays this is the array of references itself.
+    type Output<'a, const N: usize>
+    where
+        Self: 'a;
+    /// Returns mutable references to many indices at once, where the indices
+    /// are a const generic parameter.
+    ///
+    /// `N` must be the number of indices. Passing a different number of
+    /// indices, or the same index more than once, is a compile error. For
+    /// arrays, so is passing an out-of-bounds index, and no checks are done
+    /// at runtime. For slices, only the bounds are checked at runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(adt_const_params, unsized_const_params)]
+    /// #![allow(incomplete_features)]
+    /// use get_many_mut::GetManyConstMutExt;
+    ///
+    /// let mut v = [1, 2, 3, 4, 5, 6, 7, 8];
+    /// let [a, b, c] = v.get_many_const_mut::<3, { &[0, 3, 7] }>();
+    /// *a += 10;
+    /// *b += 20;
+    /// *c += 30;
+    /// assert_eq!(v, [11, 2, 3, 24, 5, 6, 7, 38]);
+    ///
+    /// let s = &mut v[..4];
+    /// assert!(s.get_many_const_mut::<2, { &[0, 3] }>().is_ok());
+    /// assert!(s.get_many_const_mut::<2, { &[0, 4] }>().is_err());
+    /// ```
+    ///
+    /// Overlapping indices do not compile:
+    ///
+    /// ```compile_fail
+    /// #![feature(adt_const_params, unsized_const_params)]
+    /// #![allow(incomplete_features)]
+    /// use get_many_mut::GetManyConstMutExt;
+    ///
+    /// let mut v = [1, 2, 3];
+    /// let [a, b] = v.get_many_const_mut::<2, { &[1, 1] }>();
+    /// ```
+    ///
+    /// Neither do out-of-bounds indices for arrays:
+    ///
+    /// ```compile_fail
+    /// #![feature(adt_const_params, unsized_const_params)]
+    /// #![allow(incomplete_features)]
+    /// use get_many_mut::GetManyConstMutExt;
+    ///
+    /// let mut v = [1, 2, 3];
+    /// let [a, b] = v.get_many_const_mut::<2, { &[0, 3] }>();
+    /// ```
+    fn get_many_const_mut<const N: usize, const INDICES: &'static [usize]>(
+        &mut self,
+    ) -> Self::Output<'_, N>;
+}
+
+impl<T> GetManyConstMutExt for [T] {
+    type Element = T;
+    type Output<'a, const N: usize>
+        = Result<[&'a mut T; N], GetManyMutError<N>>
+    where
+        T: 'a;
+    fn get_many_const_mut<const N: usize, const INDICES: &'static [usize]>(
+        &mut self,
+    ) -> Result<[&mut T; N], GetManyMutError<N>> {
+        // NB: No index can be in bounds if it is `usize::MAX`, so this only
+        // rejects indices that would fail the runtime bounds check anyway.
+        let indices = const {
+            assert!(
+                indices_are_disjoint(INDICES, usize::MAX),
+                "get_many_const_mut requires distinct indices",
+            );
+            to_array::<N>(INDICES)
+        };
+        // SAFETY: The indices were checked to be disjoint above.
+        unsafe {
+            <Self as GetManyMutExt>::get_many_unchecked_disjoint_mut(
+                self, indices,
+            )
+        }
+    }
+}
+
+impl<T, const M: usize> GetManyConstMutExt for [T; M] {
+    type Element = T;
+    type Output<'a, const N: usize>
+        = [&'a mut T; N]
+    where
+        T: 'a;
+    fn get_many_const_mut<const N: usize, const INDICES: &'static [usize]>(
+        &mut self,
+    ) -> [&mut T; N] {
+        let indices = const {
+            assert!(
+                indices_are_disjoint(INDICES, M),
+                "get_many_const_mut requires distinct, in-bounds indices",
+            );
+            to_array::<N>(INDICES)
+        };
+        // SAFETY: The indices were checked to be disjoint and in bounds above.
+        unsafe {
+            <Self as GetManyMutExt>::get_many_unchecked_mut(self, indices)
+        }
+    }
+}
+
+/// Copies `indices` into an array, panicking if it does not have exactly `N`
+/// elements.
+const fn to_array<const N: usize>(indices: &[usize]) -> [usize; N] {
+    assert!(
+        indices.len() == N,
+        "get_many_const_mut requires exactly N indices",
+    );
+    let mut array = [0; N];
+    let mut i = 0;
+    while i < N {
+        array[i] = indices[i];
+        i += 1;
+    }
+    array
+}
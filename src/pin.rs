@@ -0,0 +1,67 @@
+use crate::{GetManyMutError, GetManyMutExt};
+use core::pin::Pin;
+
+/// Extension trait for
+/// [`get_many_pin_mut`](GetManyPinMutExt::get_many_pin_mut).
+///
+/// This is separate from [`GetManyMutExt`] because it is only sound for types
+/// whose elements are structurally pinned, like slices and arrays.
+pub trait GetManyPinMutExt {
+    type Element;
+    /// Returns pinned mutable references to many indices at once.
+    ///
+    /// Returns an error if any index is out-of-bounds, or if the same index was
+    /// passed more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::{marker::PhantomPinned, pin::Pin};
+    /// use get_many_mut::GetManyPinMutExt;
+    ///
+    /// struct Node {
+    ///     value: i32,
+    ///     _pin: PhantomPinned,
+    /// }
+    ///
+    /// let mut nodes = Box::pin([
+    ///     Node { value: 1, _pin: PhantomPinned },
+    ///     Node { value: 2, _pin: PhantomPinned },
+    /// ]);
+    /// let [a, b] = nodes.as_mut().get_many_pin_mut([0, 1]).unwrap();
+    /// unsafe {
+    ///     a.get_unchecked_mut().value += 10;
+    ///     b.get_unchecked_mut().value += 20;
+    /// }
+    /// assert_eq!((nodes[0].value, nodes[1].value), (11, 22));
+    /// ```
+    fn get_many_pin_mut<const N: usize>(
+        self: Pin<&mut Self>,
+        indices: [usize; N],
+    ) -> Result<[Pin<&mut Self::Element>; N], GetManyMutError<N>>;
+}
+
+impl<T> GetManyPinMutExt for [T] {
+    type Element = T;
+    fn get_many_pin_mut<const N: usize>(
+        self: Pin<&mut Self>,
+        indices: [usize; N],
+    ) -> Result<[Pin<&mut T>; N], GetManyMutError<N>> {
+        // SAFETY: The elements of a slice are structurally pinned, and none of
+        // them are moved here.
+        let refs = unsafe { self.get_unchecked_mut() }.get_many_mut(indices)?;
+        Ok(refs.map(|elem| unsafe { Pin::new_unchecked(elem) }))
+    }
+}
+
+impl<T, const M: usize> GetManyPinMutExt for [T; M] {
+    type Element = T;
+    fn get_many_pin_mut<const N: usize>(
+        self: Pin<&mut Self>,
+        indices: [usize; N],
+    ) -> Result<[Pin<&mut T>; N], GetManyMutError<N>> {
+        // SAFETY: This is the same allocation, so it is still pinned.
+        let slice = unsafe { self.map_unchecked_mut(|arr| &mut arr[..]) };
+        <[T] as GetManyPinMutExt>::get_many_pin_mut(slice, indices)
+    }
+}
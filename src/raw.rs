@@ -0,0 +1,153 @@
+use crate::{GetManyMutError, get_many_check_valid};
+use core::ptr::NonNull;
+
+/// Extension trait for [`get_many_raw`](GetManyRawExt::get_many_raw).
+///
+/// This is implemented for raw slice pointers, for use in code that cannot
+/// form a `&mut [T]` to the whole slice, e.g. because other parts of it are
+/// borrowed. It does the same checks as
+/// [`get_many_mut`](crate::GetManyMutExt::get_many_mut), but never
+/// dereferences the pointer.
+pub trait GetManyRawExt {
+    /// The type of pointer to a single element.
+    type ElementPtr;
+    /// Returns pointers to many indices at once.
+    ///
+    /// Returns an error if any index is out-of-bounds, or if the same index was
+    /// passed more than once. A null pointer has no in-bounds indices.
+    ///
+    /// The returned pointers are only valid to use if `self` is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::ptr::NonNull;
+    /// use get_many_mut::GetManyRawExt;
+    ///
+    /// let mut v = [1, 2, 3];
+    /// let raw = NonNull::from(&mut v[..]);
+    /// let [a, c] = raw.get_many_raw([0, 2]).unwrap();
+    /// unsafe {
+    ///     core::ptr::swap(a.as_ptr(), c.as_ptr());
+    /// }
+    /// assert_eq!(v, [3, 2, 1]);
+    ///
+    /// let raw: *mut [i32] = &mut v[..];
+    /// assert!(raw.get_many_raw([0, 3]).is_err());
+    /// assert!(raw.get_many_raw([1, 1]).is_err());
+    /// ```
+    fn get_many_raw<const N: usize>(
+        self,
+        indices: [usize; N],
+    ) -> Result<[Self::ElementPtr; N], GetManyMutError<N>>;
+}
+
+impl<T> GetManyRawExt for *mut [T] {
+    type ElementPtr = *mut T;
+    fn get_many_raw<const N: usize>(
+        self,
+        indices: [usize; N],
+    ) -> Result<[*mut T; N], GetManyMutError<N>> {
+        let len = NonNull::new(self).map_or(0, raw_slice_len);
+        if get_many_check_valid(&indices, len) {
+            let ptr = self as *mut T;
+            Ok(indices.map(|idx| ptr.wrapping_add(idx)))
+        } else {
+            Err(GetManyMutError::new())
+        }
+    }
+}
+
+impl<T> GetManyRawExt for NonNull<[T]> {
+    type ElementPtr = NonNull<T>;
+    fn get_many_raw<const N: usize>(
+        self,
+        indices: [usize; N],
+    ) -> Result<[NonNull<T>; N], GetManyMutError<N>> {
+        let ptrs = self.as_ptr().get_many_raw(indices)?;
+        let mut arr = [NonNull::dangling(); N];
+        for (elem, ptr) in arr.iter_mut().zip(ptrs) {
+            // NB: This can only fail if `self` wraps around the end of the
+            // address space, which no valid slice does.
+            *elem = NonNull::new(ptr).ok_or_else(GetManyMutError::new)?;
+        }
+        Ok(arr)
+    }
+}
+
+/// Returns the length of a raw slice pointer without dereferencing it.
+// NB: `<*mut [T]>::len` is not available on our MSRV.
+fn raw_slice_len<T>(slice: NonNull<[T]>) -> usize {
+    // SAFETY: `[()]` is zero-sized and has an alignment of 1, so any non-null
+    // pointer to it is valid to dereference, and does not alias anything.
+    unsafe { (&*(slice.as_ptr() as *const [()])).len() }
+}
+
+/// A container whose elements are stored contiguously, like a slice.
+///
+/// Implementing this gives a container all methods of
+/// [`GetManyMutExt`](crate::GetManyMutExt), including ones added in the
+/// future, without having to implement them one by one. It is implemented for
+/// arrays, and `[T]` implements `GetManyMutExt` directly.
+///
+/// # Safety
+///
+/// [`as_raw_parts`](RawSliceAccess::as_raw_parts) must return a pointer and
+/// length that are valid to pass to [`slice::from_raw_parts`] for as long as
+/// `self` is borrowed.
+/// [`as_raw_parts_mut`](RawSliceAccess::as_raw_parts_mut) must return a
+/// pointer and length that are valid to pass to [`slice::from_raw_parts_mut`]
+/// for as long as `self` is mutably borrowed, and the elements must not be
+/// accessed in any other way during that time.
+///
+/// [`slice::from_raw_parts`]: core::slice::from_raw_parts
+/// [`slice::from_raw_parts_mut`]: core::slice::from_raw_parts_mut
+///
+/// # Examples
+///
+/// ```
+/// use get_many_mut::{GetManyMutExt, RawSliceAccess};
+///
+/// struct Buffer {
+///     storage: [u32; 8],
+///     len: usize,
+/// }
+///
+/// // SAFETY: The first `len` elements of `storage` are a valid slice, and
+/// // `len` is never greater than 8.
+/// unsafe impl RawSliceAccess for Buffer {
+///     type Element = u32;
+///     fn as_raw_parts(&self) -> (*const u32, usize) {
+///         (self.storage.as_ptr(), self.len)
+///     }
+///     fn as_raw_parts_mut(&mut self) -> (*mut u32, usize) {
+///         (self.storage.as_mut_ptr(), self.len)
+///     }
+/// }
+///
+/// let mut buf = Buffer { storage: [1, 2, 3, 4, 0, 0, 0, 0], len: 4 };
+/// let [a, b] = buf.get_many_mut([0, 3]).unwrap();
+/// core::mem::swap(a, b);
+/// assert_eq!(buf.storage[..4], [4, 2, 3, 1]);
+///
+/// assert!(buf.get_many_mut([0, 4]).is_err());
+/// ```
+pub unsafe trait RawSliceAccess {
+    type Element;
+    /// Returns a pointer to the first element, and the number of elements.
+    fn as_raw_parts(&self) -> (*const Self::Element, usize);
+    /// Returns a mutable pointer to the first element, and the number of
+    /// elements.
+    fn as_raw_parts_mut(&mut self) -> (*mut Self::Element, usize);
+}
+
+// SAFETY: An array is a slice of length `M`.
+unsafe impl<T, const M: usize> RawSliceAccess for [T; M] {
+    type Element = T;
+    fn as_raw_parts(&self) -> (*const T, usize) {
+        (self.as_ptr(), M)
+    }
+    fn as_raw_parts_mut(&mut self) -> (*mut T, usize) {
+        (self.as_mut_ptr(), M)
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{GetManyMutError, GetManyMutExt};
+use alloc::{rc::Rc, sync::Arc};
+use core::fmt;
+
+/// Extension trait for
+/// [`get_many_mut_if_unique`](GetManyMutRcExt::get_many_mut_if_unique).
+///
+/// This is implemented for [`Rc<[T]>`](Rc) and [`Arc<[T]>`](Arc), which can
+/// only be mutated in place if there are no other references to them.
+pub trait GetManyMutRcExt {
+    type Element;
+    /// Returns mutable references to many indices at once, if there are no
+    /// other `Rc` or `Arc` pointers (including weak ones) to the same
+    /// allocation.
+    ///
+    /// This is like [`Rc::get_mut`] followed by
+    /// [`get_many_mut`](GetManyMutExt::get_many_mut).
+    ///
+    /// Returns an error if the allocation is shared, if any index is
+    /// out-of-bounds, or if the same index was passed more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::{GetManyMutRcError, GetManyMutRcExt};
+    /// use std::sync::Arc;
+    ///
+    /// let mut snapshot: Arc<[i32]> = Arc::from([1, 2, 3]);
+    /// let [a, c] = snapshot.get_many_mut_if_unique([0, 2]).unwrap();
+    /// core::mem::swap(a, c);
+    /// assert_eq!(*snapshot, [3, 2, 1]);
+    ///
+    /// assert!(matches!(
+    ///     snapshot.get_many_mut_if_unique([1, 1]),
+    ///     Err(GetManyMutRcError::Indices(_)),
+    /// ));
+    ///
+    /// let other = Arc::clone(&snapshot);
+    /// assert!(matches!(
+    ///     snapshot.get_many_mut_if_unique([0, 2]),
+    ///     Err(GetManyMutRcError::Shared),
+    /// ));
+    /// # drop(other);
+    /// ```
+    fn get_many_mut_if_unique<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+    ) -> Result<[&mut Self::Element; N], GetManyMutRcError<N>>;
+}
+
+impl<T> GetManyMutRcExt for Rc<[T]> {
+    type Element = T;
+    fn get_many_mut_if_unique<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+    ) -> Result<[&mut T; N], GetManyMutRcError<N>> {
+        let slice = Rc::get_mut(self).ok_or(GetManyMutRcError::Shared)?;
+        slice.get_many_mut(indices).map_err(GetManyMutRcError::Indices)
+    }
+}
+
+impl<T> GetManyMutRcExt for Arc<[T]> {
+    type Element = T;
+    fn get_many_mut_if_unique<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+    ) -> Result<[&mut T; N], GetManyMutRcError<N>> {
+        let slice = Arc::get_mut(self).ok_or(GetManyMutRcError::Shared)?;
+        slice.get_many_mut(indices).map_err(GetManyMutRcError::Indices)
+    }
+}
+
+/// The error type returned by
+/// [`get_many_mut_if_unique`](GetManyMutRcExt::get_many_mut_if_unique).
+#[derive(Debug)]
+pub enum GetManyMutRcError<const N: usize> {
+    /// There are other `Rc` or `Arc` pointers to the same allocation.
+    Shared,
+    /// The indices were out-of-bounds or not distinct.
+    Indices(GetManyMutError<N>),
+}
+
+impl<const N: usize> From<GetManyMutError<N>> for GetManyMutRcError<N> {
+    fn from(err: GetManyMutError<N>) -> Self {
+        GetManyMutRcError::Indices(err)
+    }
+}
+
+impl<const N: usize> fmt::Display for GetManyMutRcError<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GetManyMutRcError::Shared => {
+                fmt::Display::fmt("the allocation is shared", f)
+            }
+            GetManyMutRcError::Indices(err) => fmt::Display::fmt(err, f),
+        }
+    }
+}
+
+#[cfg(all(feature = "std", not(feature = "core-error")))]
+impl<const N: usize> std::error::Error for GetManyMutRcError<N> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            GetManyMutRcError::Shared => None,
+            GetManyMutRcError::Indices(err) => Some(err),
+        }
+    }
+}
+
+#[cfg(feature = "core-error")]
+impl<const N: usize> core::error::Error for GetManyMutRcError<N> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            GetManyMutRcError::Shared => None,
+            GetManyMutRcError::Indices(err) => Some(err),
+        }
+    }
+}
@@ -0,0 +1,105 @@
+//! SSE2 implementation of the pairwise validity check.
+//!
+//! SSE2 is part of the x86_64 baseline, so no runtime detection is needed.
+
+use core::arch::x86_64::{
+    __m128i, _mm_and_si128, _mm_cmpeq_epi32, _mm_loadu_si128,
+    _mm_movemask_epi8, _mm_or_si128, _mm_set1_epi64x, _mm_setzero_si128,
+    _mm_shuffle_epi32,
+};
+
+/// This checks every index against each other, and against `len`.
+///
+/// The bounds checks are done one index at a time, and the pairwise
+/// inequality checks are done two indices at a time.
+///
+/// # Examples
+///
+/// This is reached by [`get_many_mut`](crate::GetManyMutExt::get_many_mut)
+/// with 4 to 32 indices. A slice of zero-sized elements can be long enough
+/// for distinct indices to have matching 32-bit halves.
+///
+/// ```
+/// use get_many_mut::GetManyMutExt;
+///
+/// const LEN: usize = 1 << 41;
+///
+/// fn check<const N: usize>(v: &mut [()]) {
+///     let mut indices = [0; N];
+///     for (i, idx) in indices.iter_mut().enumerate() {
+///         *idx = 3 * i;
+///     }
+///     assert!(v.get_many_mut(indices).is_ok());
+///
+///     for i in 0..N {
+///         for j in 0..N {
+///             let mut duplicate = indices;
+///             duplicate[j] = indices[i];
+///             assert_eq!(v.get_many_mut(duplicate).is_ok(), i == j);
+///
+///             // Only the upper halves differ.
+///             let mut high = indices;
+///             high[j] = indices[i] | 1 << 40;
+///             assert!(v.get_many_mut(high).is_ok());
+///
+///             // Only the lower halves differ.
+///             let mut low = indices;
+///             low[i] |= 1 << 40;
+///             low[j] = (indices[i] + 1) | 1 << 40;
+///             assert!(v.get_many_mut(low).is_ok());
+///         }
+///
+///         for &past_end in &[LEN, LEN | 1, usize::MAX, indices[i] | LEN] {
+///             let mut out_of_bounds = indices;
+///             out_of_bounds[i] = past_end;
+///             assert!(v.get_many_mut(out_of_bounds).is_err());
+///         }
+///     }
+/// }
+///
+/// // SAFETY: A slice of zero-sized elements can have any length.
+/// let v = unsafe {
+///     core::slice::from_raw_parts_mut(
+///         core::ptr::NonNull::dangling().as_ptr(),
+///         LEN,
+///     )
+/// };
+/// check::<4>(v);
+/// check::<5>(v);
+/// check::<8>(v);
+/// check::<13>(v);
+/// check::<31>(v);
+/// check::<32>(v);
+/// ```
+pub(crate) fn get_many_check_valid_pairwise<const N: usize>(
+    indices: &[usize; N],
+    len: usize,
+) -> bool {
+    let mut valid = true;
+    for &idx in indices {
+        valid &= idx < len;
+    }
+
+    // SAFETY: SSE2 is always available on x86_64, and `chunks_exact(2)`
+    // yields exactly 16 bytes per chunk.
+    unsafe {
+        let mut duplicates = _mm_setzero_si128();
+        for (i, &idx) in indices.iter().enumerate() {
+            let needle = _mm_set1_epi64x(idx as i64);
+            let chunks = indices[..i].chunks_exact(2);
+            for &idx2 in chunks.remainder() {
+                valid &= idx != idx2;
+            }
+            for chunk in chunks {
+                let chunk = _mm_loadu_si128(chunk.as_ptr().cast::<__m128i>());
+                // NB: There is no 64-bit equality comparison in SSE2, so
+                // compare 32-bit halves and require both halves to match.
+                let eq = _mm_cmpeq_epi32(chunk, needle);
+                let eq =
+                    _mm_and_si128(eq, _mm_shuffle_epi32(eq, 0b10_11_00_01));
+                duplicates = _mm_or_si128(duplicates, eq);
+            }
+        }
+        valid & (_mm_movemask_epi8(duplicates) == 0)
+    }
+}
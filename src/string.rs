@@ -0,0 +1,52 @@
+use crate::{GetManyMutError, GetManyMutExt};
+use core::{ops::Range, str};
+
+/// Extension trait for
+/// [`get_many_str_mut`](GetManyStrMutExt::get_many_str_mut).
+pub trait GetManyStrMutExt {
+    /// Returns mutable references to many substrings at once.
+    ///
+    /// Returns an error if any range is out-of-bounds, has its start after its
+    /// end, or does not start and end on a `char` boundary, or if any two
+    /// ranges overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyStrMutExt;
+    ///
+    /// let mut s = String::from("name=ferris;lang=rust");
+    /// let [name, lang] = s.get_many_str_mut([5..11, 17..21]).unwrap();
+    /// name.make_ascii_uppercase();
+    /// lang.make_ascii_uppercase();
+    /// assert_eq!(s, "name=FERRIS;lang=RUST");
+    ///
+    /// let mut s = String::from("ß");
+    /// assert!(s.get_many_str_mut([0..1]).is_err());
+    /// ```
+    fn get_many_str_mut<const N: usize>(
+        &mut self,
+        ranges: [Range<usize>; N],
+    ) -> Result<[&mut str; N], GetManyMutError<N>>;
+}
+
+impl GetManyStrMutExt for str {
+    fn get_many_str_mut<const N: usize>(
+        &mut self,
+        ranges: [Range<usize>; N],
+    ) -> Result<[&mut str; N], GetManyMutError<N>> {
+        for range in &ranges {
+            if !self.is_char_boundary(range.start)
+                || !self.is_char_boundary(range.end)
+            {
+                return Err(GetManyMutError::new());
+            }
+        }
+        // SAFETY: Every subslice starts and ends on a `char` boundary, so is
+        // valid UTF-8, and stays valid UTF-8 as long as it is only modified
+        // as a `str`.
+        let bytes =
+            unsafe { self.as_bytes_mut() }.get_many_ranges_mut(ranges)?;
+        Ok(bytes.map(|bytes| unsafe { str::from_utf8_unchecked_mut(bytes) }))
+    }
+}
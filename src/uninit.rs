@@ -0,0 +1,62 @@
+use crate::{GetManyMutError, GetManyMutExt};
+use core::mem::MaybeUninit;
+
+/// Extension trait for [`write_many`](WriteManyExt::write_many).
+pub trait WriteManyExt {
+    type Element;
+    /// Initializes the elements at many indices at once, returning mutable
+    /// references to the written values.
+    ///
+    /// Like [`MaybeUninit::write`], this overwrites any previous values
+    /// without dropping them.
+    ///
+    /// Returns an error if any index is out-of-bounds, or if the same index was
+    /// passed more than once. In that case, `values` are dropped and `self` is
+    /// not modified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::mem::MaybeUninit;
+    /// use get_many_mut::WriteManyExt;
+    ///
+    /// let mut buf = [MaybeUninit::<String>::uninit(), MaybeUninit::uninit()];
+    /// let [a, b] =
+    ///     buf.write_many([1, 0], [String::from("b"), String::from("a")]).unwrap();
+    /// a.push('!');
+    /// assert_eq!((&**a, &**b), ("b!", "a"));
+    ///
+    /// let [a, b] = buf.map(|s| unsafe { s.assume_init() });
+    /// assert_eq!((a, b), (String::from("a"), String::from("b!")));
+    /// ```
+    fn write_many<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+        values: [Self::Element; N],
+    ) -> Result<[&mut Self::Element; N], GetManyMutError<N>>;
+}
+
+impl<T> WriteManyExt for [MaybeUninit<T>] {
+    type Element = T;
+    fn write_many<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+        values: [T; N],
+    ) -> Result<[&mut T; N], GetManyMutError<N>> {
+        let slots = self.get_many_mut(indices)?;
+        let mut values = values.into_iter();
+        // NB: `values` has exactly as many elements as `slots`.
+        Ok(slots.map(|slot| slot.write(values.next().unwrap())))
+    }
+}
+
+impl<T, const M: usize> WriteManyExt for [MaybeUninit<T>; M] {
+    type Element = T;
+    fn write_many<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+        values: [T; N],
+    ) -> Result<[&mut T; N], GetManyMutError<N>> {
+        <[MaybeUninit<T>] as WriteManyExt>::write_many(self, indices, values)
+    }
+}
@@ -0,0 +1,331 @@
+use crate::{
+    GetManyMutError, GetManyMutExt, get_many_check_disjoint,
+    get_many_check_valid,
+};
+use alloc::{vec, vec::Vec};
+use core::{mem, ptr};
+
+const BITS: usize = usize::BITS as usize;
+
+/// Extension trait for [`Vec`] methods that remove or insert elements at many
+/// indices at once.
+pub trait GetManyMutVecExt {
+    type Element;
+    /// Removes and returns the elements at many indices at once, shifting all
+    /// elements after them to the left.
+    ///
+    /// The elements are returned in the same order as `indices`, and the
+    /// indices all refer to positions before any elements are removed. The
+    /// remaining elements are moved in a single pass.
+    ///
+    /// Returns an error if any index is out-of-bounds, or if the same index was
+    /// passed more than once. In that case, `self` is not modified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutVecExt;
+    ///
+    /// let mut v = vec!['a', 'b', 'c', 'd', 'e'];
+    /// assert_eq!(v.remove_many([3, 0]).unwrap(), ['d', 'a']);
+    /// assert_eq!(v, ['b', 'c', 'e']);
+    ///
+    /// assert!(v.remove_many([1, 1]).is_err());
+    /// assert_eq!(v, ['b', 'c', 'e']);
+    /// ```
+    fn remove_many<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+    ) -> Result<[Self::Element; N], GetManyMutError<N>>;
+    /// Removes and returns the elements at many indices at once, replacing
+    /// them with the last elements of the vector.
+    ///
+    /// The elements are returned in the same order as `indices`, and the
+    /// indices all refer to positions before any elements are removed. Each
+    /// removed element is replaced by one of the last elements that is not
+    /// itself removed. This does not preserve ordering, but is `O(N log N)`.
+    ///
+    /// Returns an error if any index is out-of-bounds, or if the same index was
+    /// passed more than once. In that case, `self` is not modified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutVecExt;
+    ///
+    /// let mut v = vec!['a', 'b', 'c', 'd', 'e'];
+    /// assert_eq!(v.swap_remove_many([0, 3]).unwrap(), ['a', 'd']);
+    /// assert_eq!(v, ['e', 'b', 'c']);
+    ///
+    /// assert!(v.swap_remove_many([3]).is_err());
+    /// assert_eq!(v, ['e', 'b', 'c']);
+    /// ```
+    fn swap_remove_many<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+    ) -> Result<[Self::Element; N], GetManyMutError<N>>;
+    /// Removes the elements at each index of `indices`, returning them as an
+    /// iterator.
+    ///
+    /// The elements are yielded in the same order as `indices`, and the
+    /// indices all refer to positions before any elements are removed. When
+    /// the iterator is dropped, any elements that were not yielded are
+    /// dropped, and the remaining elements are moved to close the gaps.
+    ///
+    /// If the returned iterator is leaked, `self` may lose (and leak)
+    /// elements arbitrarily, including elements that were not drained.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index is out-of-bounds, or if the same index appears more
+    /// than once. In that case, `self` is not modified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutVecExt;
+    ///
+    /// let mut v = vec!['a', 'b', 'c', 'd', 'e'];
+    /// let mut drain = v.drain_many([4, 1, 2]);
+    /// assert_eq!(drain.next(), Some('e'));
+    /// drop(drain);
+    /// assert_eq!(v, ['a', 'd']);
+    /// ```
+    fn drain_many<I>(&mut self, indices: I) -> DrainMany<'_, Self::Element>
+    where
+        I: IntoIterator<Item = usize>;
+    /// Returns mutable references to many indices at once, first growing
+    /// `self` so that every index is in bounds.
+    ///
+    /// New elements are created by calling `fill`, like with
+    /// [`Vec::resize_with`].
+    ///
+    /// Returns an error if the same index was passed more than once, or if an
+    /// index is too large for any `Vec` to contain that many elements. In that
+    /// case, `self` is not modified.
+    ///
+    /// Growing `self` allocates memory for every element up to the largest
+    /// index, so a large index can make the allocation fail, which aborts the
+    /// process, like with [`Vec::resize_with`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::GetManyMutVecExt;
+    ///
+    /// let mut histogram = vec![0; 2];
+    /// for [a, b] in [[0, 3], [3, 1]] {
+    ///     let [a, b] = histogram.get_or_extend_many_mut([a, b], || 0).unwrap();
+    ///     *a += 1;
+    ///     *b += 1;
+    /// }
+    /// assert_eq!(histogram, [1, 1, 0, 2]);
+    ///
+    /// assert!(histogram.get_or_extend_many_mut([5, 5], || 0).is_err());
+    /// assert!(histogram.get_or_extend_many_mut([usize::MAX - 1], || 0).is_err());
+    /// assert_eq!(histogram.len(), 4);
+    /// ```
+    fn get_or_extend_many_mut<F, const N: usize>(
+        &mut self,
+        indices: [usize; N],
+        fill: F,
+    ) -> Result<[&mut Self::Element; N], GetManyMutError<N>>
+    where
+        F: FnMut() -> Self::Element;
+}
+
+impl<T> GetManyMutVecExt for Vec<T> {
+    type Element = T;
+    fn remove_many<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+    ) -> Result<[T; N], GetManyMutError<N>> {
+        let len = self.len();
+        if !get_many_check_valid(&indices, len) {
+            return Err(GetManyMutError::new());
+        }
+        let mut sorted = indices;
+        sorted.sort_unstable();
+
+        let ptr: *mut T = self.as_mut_ptr();
+        let mut arr: mem::MaybeUninit<[T; N]> = mem::MaybeUninit::uninit();
+        let arr_ptr: *mut T = arr.as_mut_ptr().cast();
+
+        // SAFETY: `indices` are distinct and in bounds, so each element is
+        // moved out exactly once, and the holes are closed before the length
+        // is restored. Nothing here can panic.
+        unsafe {
+            for (i, &idx) in indices.iter().enumerate() {
+                arr_ptr.add(i).write(ptr::read(ptr.add(idx)));
+            }
+            for (removed, &idx) in sorted.iter().enumerate() {
+                let end = sorted.get(removed + 1).copied().unwrap_or(len);
+                // NB: `removed` elements before `idx` have been removed
+                // already, and `idx` itself is being removed.
+                ptr::copy(
+                    ptr.add(idx + 1),
+                    ptr.add(idx - removed),
+                    end - idx - 1,
+                );
+            }
+            self.set_len(len - N);
+            Ok(arr.assume_init())
+        }
+    }
+    fn swap_remove_many<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+    ) -> Result<[T; N], GetManyMutError<N>> {
+        if !get_many_check_valid(&indices, self.len()) {
+            return Err(GetManyMutError::new());
+        }
+        let mut order = [0; N];
+        for (i, position) in order.iter_mut().enumerate() {
+            *position = i;
+        }
+        // NB: Removing in descending order means the last element is never
+        // one that still has to be removed.
+        order.sort_unstable_by(|&a, &b| indices[b].cmp(&indices[a]));
+
+        let mut arr: mem::MaybeUninit<[T; N]> = mem::MaybeUninit::uninit();
+        let arr_ptr: *mut T = arr.as_mut_ptr().cast();
+
+        // SAFETY: `order` is a permutation of `0..N`, so every element of
+        // `arr` is written exactly once.
+        unsafe {
+            for &i in &order {
+                arr_ptr.add(i).write(self.swap_remove(indices[i]));
+            }
+            Ok(arr.assume_init())
+        }
+    }
+    // NB: All indices have to be validated before `self` is modified.
+    #[allow(clippy::needless_collect)]
+    fn drain_many<I>(&mut self, indices: I) -> DrainMany<'_, T>
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        let len = self.len();
+        let mut removed = vec![0; len / BITS + (len % BITS != 0) as usize];
+        let indices: Vec<usize> = indices
+            .into_iter()
+            .inspect(|&idx| {
+                assert!(idx < len, "drain_many index {} out of bounds", idx);
+                let word = &mut removed[idx / BITS];
+                let bit = 1 << (idx % BITS);
+                assert!(*word & bit == 0, "drain_many index {} repeated", idx);
+                *word |= bit;
+            })
+            .collect();
+        // SAFETY: The elements are only accessed through `DrainMany` until it
+        // restores the length.
+        unsafe { self.set_len(0) };
+        DrainMany { vec: self, len, indices: indices.into_iter(), removed }
+    }
+    fn get_or_extend_many_mut<F, const N: usize>(
+        &mut self,
+        indices: [usize; N],
+        fill: F,
+    ) -> Result<[&mut T; N], GetManyMutError<N>>
+    where
+        F: FnMut() -> T,
+    {
+        if !get_many_check_disjoint(&indices) {
+            return Err(GetManyMutError::new());
+        }
+        if let Some(&max) = indices.iter().max() {
+            // NB: A `Vec` can't hold more than `isize::MAX` bytes, so reject
+            // indices it can't grow to before trying to allocate.
+            let max_len = match mem::size_of::<T>() {
+                0 => usize::MAX,
+                size => isize::MAX as usize / size,
+            };
+            let min_len = match max.checked_add(1) {
+                Some(min_len) if min_len <= max_len => min_len,
+                _ => return Err(GetManyMutError::new()),
+            };
+            if min_len > self.len() {
+                self.resize_with(min_len, fill);
+            }
+        }
+        // SAFETY: The indices are distinct, and in bounds after resizing.
+        unsafe {
+            Ok(<[T] as GetManyMutExt>::get_many_unchecked_mut(self, indices))
+        }
+    }
+}
+
+/// A draining iterator over the elements at many indices of a `Vec`.
+///
+/// This `struct` is created by the [`drain_many`] method.
+///
+/// [`drain_many`]: GetManyMutVecExt::drain_many
+pub struct DrainMany<'a, T> {
+    /// The length of `vec` is 0 until this is dropped.
+    vec: &'a mut Vec<T>,
+    len: usize,
+    indices: vec::IntoIter<usize>,
+    removed: Vec<usize>,
+}
+
+impl<'a, T> DrainMany<'a, T> {
+    fn is_removed(&self, idx: usize) -> bool {
+        self.removed[idx / BITS] & (1 << (idx % BITS)) != 0
+    }
+}
+
+impl<'a, T> Iterator for DrainMany<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let idx = self.indices.next()?;
+        // SAFETY: `idx` is in bounds, and each index is only yielded once.
+        unsafe { Some(ptr::read(self.vec.as_ptr().add(idx))) }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for DrainMany<'a, T> {
+    fn next_back(&mut self) -> Option<T> {
+        let idx = self.indices.next_back()?;
+        // SAFETY: `idx` is in bounds, and each index is only yielded once.
+        unsafe { Some(ptr::read(self.vec.as_ptr().add(idx))) }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for DrainMany<'a, T> {}
+
+impl<'a, T> Drop for DrainMany<'a, T> {
+    fn drop(&mut self) {
+        /// Moves the remaining elements to close the gaps, even if dropping
+        /// an element panics.
+        struct DropGuard<'r, 'a, T>(&'r mut DrainMany<'a, T>);
+
+        impl<'r, 'a, T> Drop for DropGuard<'r, 'a, T> {
+            fn drop(&mut self) {
+                let drain = &mut *self.0;
+                let ptr: *mut T = drain.vec.as_mut_ptr();
+                let mut kept = 0;
+                for idx in 0..drain.len {
+                    if !drain.is_removed(idx) {
+                        // SAFETY: `kept <= idx`, and both are in bounds.
+                        unsafe { ptr::copy(ptr.add(idx), ptr.add(kept), 1) };
+                        kept += 1;
+                    }
+                }
+                // SAFETY: The first `kept` elements are initialized.
+                unsafe { drain.vec.set_len(kept) };
+            }
+        }
+
+        let guard = DropGuard(self);
+        let ptr: *mut T = guard.0.vec.as_mut_ptr();
+        for idx in &mut guard.0.indices {
+            // SAFETY: `idx` is in bounds, and has not been yielded.
+            unsafe { ptr::drop_in_place(ptr.add(idx)) };
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use crate::{GetManyMutError, GetManyMutExt};
+use core::{
+    iter::Map,
+    mem,
+    ops::{Index, IndexMut},
+    slice,
+};
+
+/// A view of `N` distinct elements of a slice.
+///
+/// The elements are indexed by their position in the selection, not in the
+/// underlying slice. Unlike a plain `[&mut T; N]`, this can be passed around,
+/// indexed, iterated, and split like a slice of its own.
+///
+/// # Examples
+///
+/// ```
+/// use get_many_mut::DisjointSliceView;
+///
+/// fn normalize(mut view: DisjointSliceView<'_, f64, 3>) {
+///     let sum: f64 = view.iter().sum();
+///     for elem in &mut view {
+///         *elem /= sum;
+///     }
+/// }
+///
+/// let mut v = [1.0, 5.0, 2.0, 1.0];
+/// let mut view = DisjointSliceView::new(&mut v, [0, 2, 3]).unwrap();
+/// view.swap(0, 1);
+/// assert_eq!(view[0], 2.0);
+/// normalize(view);
+/// assert_eq!(v, [0.5, 5.0, 0.25, 0.25]);
+/// ```
+pub struct DisjointSliceView<'a, T, const N: usize> {
+    refs: [&'a mut T; N],
+}
+
+impl<'a, T, const N: usize> DisjointSliceView<'a, T, N> {
+    /// Creates a view of the elements at `indices`, in that order.
+    ///
+    /// Returns an error if any index is out-of-bounds, or if the same index
+    /// was passed more than once.
+    pub fn new(
+        slice: &'a mut [T],
+        indices: [usize; N],
+    ) -> Result<Self, GetManyMutError<N>> {
+        slice.get_many_mut(indices).map(Self::from)
+    }
+
+    /// Returns the number of elements in the view.
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns `true` if the view has no elements.
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Returns a reference to the element at `pos`, or `None` if it is
+    /// out-of-bounds.
+    pub fn get(&self, pos: usize) -> Option<&T> {
+        self.refs.get(pos).map(|elem| &**elem)
+    }
+
+    /// Returns a mutable reference to the element at `pos`, or `None` if it
+    /// is out-of-bounds.
+    pub fn get_mut(&mut self, pos: usize) -> Option<&mut T> {
+        self.refs.get_mut(pos).map(|elem| &mut **elem)
+    }
+
+    /// Returns an iterator over the elements.
+    pub fn iter(&self) -> Iter<'_, 'a, T> {
+        self.refs.iter().map(|elem| &**elem)
+    }
+
+    /// Returns an iterator over mutable references to the elements.
+    pub fn iter_mut(&mut self) -> IterMut<'_, 'a, T> {
+        self.refs.iter_mut().map(|elem| &mut **elem)
+    }
+
+    /// Swaps the elements at positions `a` and `b`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out-of-bounds.
+    pub fn swap(&mut self, a: usize, b: usize) {
+        assert!(a < N && b < N, "swap index out of bounds");
+        if let Ok([a, b]) = self.refs.get_many_mut([a, b]) {
+            mem::swap(&mut **a, &mut **b);
+        }
+    }
+
+    /// Returns the references to the elements, with the positions of the view
+    /// as indices.
+    ///
+    /// This can be used to split the view, e.g. with
+    /// [`split_at_mut`](slice::split_at_mut).
+    pub fn as_mut_slice(&mut self) -> &mut [&'a mut T] {
+        &mut self.refs
+    }
+
+    /// Splits the view into one of the first `K` elements, and one of the
+    /// remaining `L` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `K + L != N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::DisjointSliceView;
+    ///
+    /// let mut v = [1, 2, 3, 4];
+    /// let view = DisjointSliceView::new(&mut v, [3, 2, 0]).unwrap();
+    /// let (mut first, mut rest) = view.split::<1, 2>();
+    /// first[0] += rest[0] + rest[1];
+    /// assert_eq!(v, [1, 2, 3, 8]);
+    /// ```
+    pub fn split<const K: usize, const L: usize>(
+        self,
+    ) -> (DisjointSliceView<'a, T, K>, DisjointSliceView<'a, T, L>) {
+        assert!(K + L == N, "split sizes must add up to the view's length");
+        let mut refs = IntoIterator::into_iter(self.refs);
+        let first = [(); K].map(|()| refs.next().expect("K <= N"));
+        let rest = [(); L].map(|()| refs.next().expect("K + L == N"));
+        (first.into(), rest.into())
+    }
+
+    /// Returns the references to the elements.
+    pub fn into_inner(self) -> [&'a mut T; N] {
+        self.refs
+    }
+}
+
+impl<'a, T, const N: usize> From<[&'a mut T; N]>
+    for DisjointSliceView<'a, T, N>
+{
+    fn from(refs: [&'a mut T; N]) -> Self {
+        Self { refs }
+    }
+}
+
+impl<'a, T, const N: usize> Index<usize> for DisjointSliceView<'a, T, N> {
+    type Output = T;
+
+    fn index(&self, pos: usize) -> &T {
+        self.refs[pos]
+    }
+}
+
+impl<'a, T, const N: usize> IndexMut<usize> for DisjointSliceView<'a, T, N> {
+    fn index_mut(&mut self, pos: usize) -> &mut T {
+        self.refs[pos]
+    }
+}
+
+/// An iterator over the elements of a [`DisjointSliceView`].
+type Iter<'b, 'a, T> =
+    Map<slice::Iter<'b, &'a mut T>, fn(&'b &'a mut T) -> &'b T>;
+
+/// An iterator over mutable references to the elements of a
+/// [`DisjointSliceView`].
+type IterMut<'b, 'a, T> =
+    Map<slice::IterMut<'b, &'a mut T>, fn(&'b mut &'a mut T) -> &'b mut T>;
+
+impl<'a, T, const N: usize> IntoIterator for DisjointSliceView<'a, T, N> {
+    type Item = &'a mut T;
+    type IntoIter = core::array::IntoIter<&'a mut T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIterator::into_iter(self.refs)
+    }
+}
+
+impl<'b, 'a, T, const N: usize> IntoIterator
+    for &'b mut DisjointSliceView<'a, T, N>
+{
+    type Item = &'b mut T;
+    type IntoIter = IterMut<'b, 'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<'b, 'a, T, const N: usize> IntoIterator
+    for &'b DisjointSliceView<'a, T, N>
+{
+    type Item = &'b T;
+    type IntoIter = Iter<'b, 'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{GetManyMutError, get_many_check_valid};
+
+/// Extension trait for
+/// [`zip_get_many_mut`](ZipGetManyMutExt::zip_get_many_mut).
+///
+/// This is implemented for tuples of two or three mutable slices, e.g.
+/// parallel slices in a struct-of-arrays layout.
+pub trait ZipGetManyMutExt {
+    /// A tuple of mutable references, one to an element of each slice.
+    type Item;
+    /// Returns tuples of mutable references to many indices at once, one
+    /// reference to each slice per index.
+    ///
+    /// The indices are checked once, against the length of the shortest
+    /// slice, like with [`Iterator::zip`].
+    ///
+    /// Returns an error if any index is out-of-bounds of any slice, or if the
+    /// same index was passed more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use get_many_mut::ZipGetManyMutExt;
+    ///
+    /// let positions = &mut [0, 10, 20];
+    /// let velocities = &mut [1, 2, 3];
+    ///
+    /// for (pos, vel) in (&mut positions[..], &mut velocities[..])
+    ///     .zip_get_many_mut([0, 2])
+    ///     .unwrap()
+    /// {
+    ///     *pos += *vel;
+    ///     *vel = 0;
+    /// }
+    /// assert_eq!(positions, &[1, 10, 23]);
+    /// assert_eq!(velocities, &[0, 2, 0]);
+    ///
+    /// assert!(
+    ///     (&mut positions[..], &mut velocities[..2])
+    ///         .zip_get_many_mut([2])
+    ///         .is_err()
+    /// );
+    /// ```
+    fn zip_get_many_mut<const N: usize>(
+        self,
+        indices: [usize; N],
+    ) -> Result<[Self::Item; N], GetManyMutError<N>>;
+}
+
+impl<'a, A, B> ZipGetManyMutExt for (&'a mut [A], &'a mut [B]) {
+    type Item = (&'a mut A, &'a mut B);
+    fn zip_get_many_mut<const N: usize>(
+        self,
+        indices: [usize; N],
+    ) -> Result<[Self::Item; N], GetManyMutError<N>> {
+        let (a, b) = self;
+        if !get_many_check_valid(&indices, a.len().min(b.len())) {
+            return Err(GetManyMutError::new());
+        }
+
+        let (a, b) = (a.as_mut_ptr(), b.as_mut_ptr());
+        // SAFETY: The indices are distinct and in bounds of every slice.
+        Ok(indices.map(|idx| unsafe { (&mut *a.add(idx), &mut *b.add(idx)) }))
+    }
+}
+
+impl<'a, A, B, C> ZipGetManyMutExt for (&'a mut [A], &'a mut [B], &'a mut [C]) {
+    type Item = (&'a mut A, &'a mut B, &'a mut C);
+    fn zip_get_many_mut<const N: usize>(
+        self,
+        indices: [usize; N],
+    ) -> Result<[Self::Item; N], GetManyMutError<N>> {
+        let (a, b, c) = self;
+        if !get_many_check_valid(&indices, a.len().min(b.len()).min(c.len())) {
+            return Err(GetManyMutError::new());
+        }
+
+        let (a, b, c) = (a.as_mut_ptr(), b.as_mut_ptr(), c.as_mut_ptr());
+        // SAFETY: The indices are distinct and in bounds of every slice.
+        Ok(indices.map(|idx| unsafe {
+            (&mut *a.add(idx), &mut *b.add(idx), &mut *c.add(idx))
+        }))
+    }
+}